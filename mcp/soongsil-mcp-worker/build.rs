@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/date.rs"]
+mod date;
+#[path = "src/front_matter.rs"]
+mod front_matter;
+#[allow(dead_code)]
+#[path = "src/hangul.rs"]
+mod hangul;
+
+use date::CivilDate;
+use front_matter::parse_front_matter as front_matter;
+use hangul::compose_hangul;
+
+const REQUIRED_METADATA: &[&str] = &[
+    "source_pdf",
+    "normalized_from_raw_md",
+    "normalized_at",
+    "last_verified_against_pdf",
+];
+const DATE_METADATA: &[&str] = &["normalized_at", "last_verified_against_pdf"];
+const LAW_REFERENCE_PDF: &str = "학칙.pdf";
+const LAW_REFERENCE_FILES: &[&str] = &[
+    "law-topic-index.md",
+    "law-articles.md",
+    "law-numeric-rules.md",
+];
+const ARTICLE_COLUMNS: &[&str] = &["조문", "조문 범위"];
+const PAGE_COLUMNS: &[&str] = &["페이지", "본칙 페이지", "근거 페이지"];
+
+struct Document {
+    name: String,
+    text: String,
+    normalized: bool,
+}
+
+#[derive(Default)]
+struct Report {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Report {
+    fn error(&mut self, doc: &Document, line: usize, message: String) {
+        self.errors.push(format!("{}:{line}: {message}", doc.name));
+    }
+
+    fn warning(&mut self, doc: &Document, message: String) {
+        self.warnings.push(format!("{}: {message}", doc.name));
+    }
+}
+
+fn embedded_paths(manifest_dir: &Path) -> Vec<PathBuf> {
+    let lib = fs::read_to_string(manifest_dir.join("src/lib.rs")).expect("read src/lib.rs");
+    let mut paths = Vec::new();
+    let mut rest = lib.as_str();
+    while let Some(start) = rest.find("include_str!(\"") {
+        rest = &rest[start + "include_str!(\"".len()..];
+        let Some(end) = rest.find('"') else {
+            break;
+        };
+        paths.push(manifest_dir.join("src").join(&rest[..end]));
+        rest = &rest[end..];
+    }
+    paths
+}
+
+fn split_pipe_row(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_start_matches('|')
+        .trim_end_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+fn is_separator_row(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with('|')
+        && split_pipe_row(trimmed)
+            .iter()
+            .all(|cell| !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':')))
+}
+
+fn page_heading(line: &str) -> Option<u32> {
+    let digits: String = line
+        .trim()
+        .strip_prefix("## p.")?
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+fn file_name(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+fn document_pdf(doc: &Document) -> Option<String> {
+    if LAW_REFERENCE_FILES.contains(&file_name(&doc.name).as_str()) {
+        return Some(LAW_REFERENCE_PDF.to_string());
+    }
+    front_matter(&doc.text)
+        .get("source_pdf")
+        .map(|path| file_name(path))
+}
+
+fn page_numbers_after(text: &str) -> Vec<u32> {
+    let mut pages = Vec::new();
+    let mut rest = text;
+    while let Some(idx) = rest.find("p.") {
+        let before = rest[..idx].chars().next_back();
+        rest = &rest[idx + 2..];
+        if before.map(|c| c.is_ascii_alphanumeric()).unwrap_or(false) {
+            continue;
+        }
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        let Ok(first) = digits.parse::<u32>() else {
+            continue;
+        };
+        pages.push(first);
+        let tail = rest[digits.len()..].strip_prefix('~').map(|t| t.trim_start_matches("p."));
+        if let Some(tail) = tail {
+            let digits: String = tail.chars().take_while(char::is_ascii_digit).collect();
+            if let Ok(last) = digits.parse::<u32>() {
+                pages.push(last);
+            }
+        }
+    }
+    pages
+}
+
+fn check_metadata(doc: &Document, report: &mut Report) {
+    if !doc.normalized {
+        return;
+    }
+    let fields = front_matter(&doc.text);
+    for key in REQUIRED_METADATA {
+        match fields.get(*key) {
+            None => report.error(doc, 1, format!("필수 메타데이터 `{key}` 누락")),
+            Some(value) if DATE_METADATA.contains(key) && CivilDate::parse_iso(value).is_none() => {
+                report.error(doc, 1, format!("`{key}` 값 `{value}`이 YYYY-MM-DD 형식의 유효한 날짜가 아님"))
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+fn check_pages(doc: &Document, report: &mut Report) -> Option<u32> {
+    let mut expected = 1;
+    let mut last = None;
+    for (idx, line) in doc.text.lines().enumerate() {
+        let Some(page) = page_heading(line) else {
+            continue;
+        };
+        if page != expected {
+            report.error(
+                doc,
+                idx + 1,
+                format!("`## p.{page}` heading 순서 오류 (p.{expected} 기대, 누락 또는 중복)"),
+            );
+        }
+        expected = page + 1;
+        last = Some(page);
+    }
+
+    if let Some(total) = front_matter(&doc.text)
+        .get("total_pages")
+        .and_then(|value| value.parse::<u32>().ok())
+    {
+        if last != Some(total) {
+            report.error(
+                doc,
+                1,
+                format!("total_pages {total}와 마지막 `## p.N` heading({last:?})이 다름"),
+            );
+        }
+        return Some(total);
+    }
+    if last.is_none() && doc.normalized && !tables_carry_pages(&doc.text) {
+        report.warning(doc, "`## p.N` 페이지 heading도, 표의 페이지 컬럼도 없음".to_string());
+    }
+    last
+}
+
+fn tables_carry_pages(text: &str) -> bool {
+    let lines: Vec<&str> = text.lines().collect();
+    lines.windows(2).all(|pair| {
+        let is_header = pair[0].trim().starts_with('|') && is_separator_row(pair[1]);
+        !is_header
+            || split_pipe_row(pair[0])
+                .iter()
+                .any(|header| PAGE_COLUMNS.contains(&header.as_str()))
+    })
+}
+
+fn check_tables(doc: &Document, report: &mut Report) {
+    let lines: Vec<&str> = doc.text.lines().collect();
+    let mut in_code = false;
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let stripped = lines[idx].trim();
+        if stripped.starts_with("```") {
+            in_code = !in_code;
+        }
+        let is_header = !in_code
+            && stripped.starts_with('|')
+            && lines.get(idx + 1).map(|next| is_separator_row(next)).unwrap_or(false);
+        if !is_header {
+            idx += 1;
+            continue;
+        }
+
+        let headers = split_pipe_row(stripped);
+        let separator = split_pipe_row(lines[idx + 1]);
+        if separator.len() != headers.len() {
+            report.error(
+                doc,
+                idx + 2,
+                format!("구분선 컬럼 수 {}가 헤더 {}와 다름", separator.len(), headers.len()),
+            );
+        }
+        if headers.iter().any(String::is_empty) {
+            report.error(doc, idx + 1, "빈 컬럼명이 있음".to_string());
+        }
+        let article_column = headers
+            .iter()
+            .position(|header| ARTICLE_COLUMNS.contains(&header.as_str()));
+        let mut seen_articles: HashMap<String, usize> = HashMap::new();
+
+        idx += 2;
+        while idx < lines.len() && lines[idx].trim().starts_with('|') {
+            let cells = split_pipe_row(lines[idx]);
+            if cells.len() != headers.len() {
+                report.error(
+                    doc,
+                    idx + 1,
+                    format!("ragged row: 셀 {}개, 헤더 {}개", cells.len(), headers.len()),
+                );
+            }
+            if let Some(article) = article_column.and_then(|col| cells.get(col)) {
+                if let Some(first) = seen_articles.insert(article.clone(), idx + 1) {
+                    report.error(
+                        doc,
+                        idx + 1,
+                        format!("중복 조문 `{article}` (line {first}에 이미 있음)"),
+                    );
+                }
+            }
+            idx += 1;
+        }
+    }
+}
+
+fn check_article_cards(doc: &Document, report: &mut Report) {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for (idx, line) in doc.text.lines().enumerate() {
+        let Some(title) = line.trim().strip_prefix("### 제") else {
+            continue;
+        };
+        let key: String = title.chars().take_while(|c| *c != '(').collect();
+        if let Some(first) = seen.insert(key.clone(), idx + 1) {
+            report.error(
+                doc,
+                idx + 1,
+                format!("중복 조문 카드 `제{key}` (line {first}에 이미 있음)"),
+            );
+        }
+    }
+}
+
+fn check_page_references(doc: &Document, page_counts: &HashMap<String, u32>, report: &mut Report) {
+    let own_pdf = document_pdf(doc);
+    let mut in_front_matter = true;
+
+    for (idx, line) in doc.text.lines().enumerate() {
+        if line.starts_with("## ") {
+            in_front_matter = false;
+        }
+        if in_front_matter || page_heading(line).is_some() {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find('(') {
+            rest = &rest[start + 1..];
+            let Some(end) = rest.find(')') else {
+                break;
+            };
+            let inner = &rest[..end];
+            if let Some((pdf, pages)) = inner.split_once(".pdf,") {
+                let pdf = format!("{}.pdf", compose_hangul(pdf.trim()));
+                if let Some(count) = page_counts.get(&pdf) {
+                    for page in page_numbers_after(pages) {
+                        if page == 0 || page > *count {
+                            report.error(
+                                doc,
+                                idx + 1,
+                                format!("orphan page reference `{pdf}, p.{page}` ({pdf}는 {count}쪽)"),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if line.contains(".pdf,") {
+            continue;
+        }
+        let Some(count) = own_pdf.as_ref().and_then(|pdf| page_counts.get(pdf)) else {
+            continue;
+        };
+        for page in page_numbers_after(line) {
+            if page == 0 || page > *count {
+                report.error(
+                    doc,
+                    idx + 1,
+                    format!("orphan page reference `p.{page}` (원문 {count}쪽)"),
+                );
+            }
+        }
+    }
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR"));
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/date.rs");
+    println!("cargo:rerun-if-changed=src/front_matter.rs");
+    println!("cargo:rerun-if-changed=src/hangul.rs");
+
+    let repo_root = fs::canonicalize(manifest_dir.join("../..")).unwrap_or_default();
+    let mut documents = Vec::new();
+    for path in embedded_paths(&manifest_dir) {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("embedded document {} unreadable: {err}", path.display()));
+        let display = fs::canonicalize(&path)
+            .ok()
+            .and_then(|full| full.strip_prefix(&repo_root).map(Path::to_path_buf).ok())
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        documents.push(Document {
+            name: display.clone(),
+            normalized: display.starts_with("knowledge/normalized-md/")
+                || display.starts_with("knowledge/snapshots/"),
+            text,
+        });
+    }
+
+    let mut report = Report::default();
+    let mut page_counts: HashMap<String, u32> = HashMap::new();
+    for doc in &documents {
+        check_metadata(doc, &mut report);
+        if let (Some(pages), Some(pdf)) = (check_pages(doc, &mut report), document_pdf(doc)) {
+            let entry = page_counts.entry(pdf).or_insert(0);
+            *entry = (*entry).max(pages);
+        }
+        check_tables(doc, &mut report);
+        check_article_cards(doc, &mut report);
+    }
+    for doc in &documents {
+        check_page_references(doc, &page_counts, &mut report);
+    }
+
+    for warning in &report.warnings {
+        println!("cargo:warning=corpus: {warning}");
+    }
+    if !report.errors.is_empty() {
+        panic!(
+            "corpus integrity check failed ({} errors; see references/normalization-rules.md):\n{}",
+            report.errors.len(),
+            report.errors.join("\n")
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::catalog::{self, PartMatch, Program, Scope};
+use crate::{article_citation, gaps, number_to_i64};
+
+const AREAS: &[(&str, &str)] = &[
+    ("msc", "MSC"),
+    ("liberal", "전문교양"),
+    ("engineering", "공학주제"),
+    ("design", "설계"),
+];
+static SINGLE_FROM_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{4})학년도 입학자부터").expect("SINGLE_FROM_RE compile failure"));
+
+struct AccreditationRow {
+    label: String,
+    legacy_name: Option<String>,
+    scheme: String,
+    status: String,
+    citation: String,
+}
+
+impl AccreditationRow {
+    fn active(&self) -> bool {
+        self.status.starts_with("운영")
+    }
+
+    fn single_accreditation_from(&self) -> Option<i64> {
+        SINGLE_FROM_RE.captures(&self.status)?[1].parse().ok()
+    }
+}
+
+pub(crate) struct AccreditationInput {
+    pub earned: Vec<(&'static str, Option<i64>)>,
+    pub required: Vec<(&'static str, Option<i64>)>,
+    pub required_courses_completed: Option<bool>,
+}
+
+pub(crate) fn parse_area_credits(
+    args: &Map<String, Value>,
+    key: &str,
+) -> std::result::Result<Vec<(&'static str, Option<i64>)>, String> {
+    let object = match args.get(key) {
+        None => return Ok(AREAS.iter().map(|(_, label)| (*label, None)).collect()),
+        Some(value) => value.as_object().ok_or_else(|| format!("'{key}' must be an object"))?,
+    };
+    AREAS
+        .iter()
+        .map(|(field, label)| match object.get(*field) {
+            None => Ok((*label, None)),
+            Some(value) => number_to_i64(value)
+                .map(|credits| (*label, Some(credits)))
+                .ok_or_else(|| format!("'{key}.{field}' must be an integer")),
+        })
+        .collect()
+}
+
+fn accreditation_rows(as_of: Option<&str>) -> Vec<AccreditationRow> {
+    let Some(table) = catalog::catalog_table("Engineering Accreditation", as_of) else {
+        return vec![];
+    };
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(AccreditationRow {
+                label: table.cell(row, "학과/학부")?.to_string(),
+                legacy_name: table
+                    .cell(row, "구 명칭")
+                    .filter(|name| !name.is_empty() && *name != "-")
+                    .map(str::to_string),
+                scheme: table.cell(row, "인증제")?.to_string(),
+                status: table.cell(row, "상태")?.to_string(),
+                citation: article_citation(table.cell(row, "근거").unwrap_or_default()),
+            })
+        })
+        .collect()
+}
+
+fn find_row<'a>(rows: &'a [AccreditationRow], program: &Program) -> Option<(&'a AccreditationRow, PartMatch)> {
+    let major = program.major.as_deref();
+    let input_key = catalog::name_key(program.input_department());
+    let scored: Vec<(&AccreditationRow, PartMatch)> = rows
+        .iter()
+        .map(|row| (row, Scope::Listed(vec![row.label.clone()]).best_match(&program.department, major)))
+        .collect();
+    scored
+        .iter()
+        .find(|(row, matched)| *matched == PartMatch::Full && row.active())
+        .or_else(|| {
+            scored.iter().find(|(row, matched)| {
+                *matched == PartMatch::Full
+                    && row.legacy_name.as_deref().map(catalog::name_key) == Some(input_key.clone())
+            })
+        })
+        .or_else(|| {
+            scored
+                .iter()
+                .find(|(row, matched)| *matched == PartMatch::Full && row.legacy_name.is_none())
+        })
+        .or_else(|| scored.iter().find(|(_, matched)| *matched == PartMatch::Full))
+        .or_else(|| scored.iter().find(|(_, matched)| *matched == PartMatch::Partial))
+        .copied()
+}
+
+pub(crate) fn evaluate_accreditation(
+    program: &Program,
+    admission_year: Option<i64>,
+    input: &AccreditationInput,
+    as_of: Option<&str>,
+) -> Value {
+    let article = "(학칙.pdf, p.2, 제5조의2)";
+    let known_gap = gaps::gap_named("공학교육인증 이수학점", as_of).map(|gap| gaps::gap_summary(&gap));
+    let rows = accreditation_rows(as_of);
+
+    let areas: Vec<Value> = input
+        .earned
+        .iter()
+        .zip(&input.required)
+        .map(|((area, earned), (_, required))| {
+            json!({
+                "area": area,
+                "earned": earned,
+                "required": required,
+                "gap": earned.zip(*required).map(|(earned, required)| (required - earned).max(0)),
+                "source": required.map(|_| "입력값(accreditation_required)"),
+            })
+        })
+        .collect();
+    let mut gap = Map::new();
+    for area in &areas {
+        if let Some(value) = area["gap"].as_i64() {
+            gap.insert(area["area"].as_str().unwrap_or_default().to_string(), json!(value));
+        }
+    }
+
+    if !program.known {
+        return json!({
+            "judgement": "판정 불가",
+            "applicable": false,
+            "reason": format!(
+                "학과(부) '{}'를 프로그램 카탈로그에서 찾지 못해 공학교육인증제 운영 학과(부)인지 판정할 수 없음",
+                program.label()
+            ),
+            "program": program.summary(),
+            "areas": areas,
+            "gap": gap,
+            "citation": article,
+            "notes": ["department에 카탈로그 학과(부)·전공 이름 또는 별칭을 입력 (학칙.pdf, p.53)"],
+        });
+    }
+    let Some((row, matched)) = find_row(&rows, program) else {
+        return json!({
+            "judgement": "불가",
+            "applicable": false,
+            "reason": format!("제5조의2 공학교육인증제 운영 학과(부)가 아님: {}", program.label()),
+            "areas": areas,
+            "gap": gap,
+            "citation": article,
+            "notes": [format!(
+                "공학교육인증제 운영 학과(부): {} (학칙.pdf, p.2, 제5조의2 1항)",
+                rows.iter().filter(|row| row.active()).map(|row| row.label.as_str()).collect::<Vec<_>>().join(", ")
+            )],
+        });
+    };
+
+    let mut notes = vec![
+        "인증 이수학점은 졸업학점과 별도 요건이며, 같은 과목이 전공·교양 학점과 인증 영역에 함께 산입될 수 있음".to_string(),
+        "공학교육인증제 세부사항은 내규 소관 (학칙.pdf, p.3, 제5조의2 4항)".to_string(),
+    ];
+    let mut unverified = Vec::new();
+    let (judgement, reason) = if matched == PartMatch::Partial {
+        (
+            "판정 불가",
+            format!("{} 중 인증 대상 전공({})인지 확인 필요: department에 전공 입력", program.department, row.label),
+        )
+    } else if !row.active() {
+        notes.push("종료일 이전 졸업자의 인증 여부는 학과 확인 필요".to_string());
+        ("불가", format!("{} {}: {}", row.label, row.scheme, row.status))
+    } else {
+        match (admission_year, row.single_accreditation_from()) {
+            (Some(year), Some(from)) if year < from => notes.push(format!(
+                "{year}학년도 입학자: 단일인증 프로그램은 {from}학년도 입학자부터 시행 {}",
+                row.citation
+            )),
+            (None, Some(from)) => {
+                notes.push(format!("admission_year 미입력: {from}학년도 이후 입학자 단일인증 프로그램 기준으로 가정"))
+            }
+            _ => {}
+        }
+        let missing_required: Vec<&str> = input
+            .required
+            .iter()
+            .filter(|(_, required)| required.is_none())
+            .map(|(area, _)| *area)
+            .collect();
+        let missing_earned: Vec<&str> = input
+            .earned
+            .iter()
+            .filter(|(_, earned)| earned.is_none())
+            .map(|(area, _)| *area)
+            .collect();
+        if !missing_required.is_empty() {
+            unverified.push(format!("인증 이수학점 기준({})", missing_required.join(", ")));
+        }
+        if !missing_earned.is_empty() {
+            unverified.push(format!("인증 영역 이수학점({})", missing_earned.join(", ")));
+        }
+        if input.required_courses_completed.is_none() {
+            unverified.push("인증필수 교과목".to_string());
+        }
+        let total_gap: i64 = gap.values().filter_map(Value::as_i64).sum();
+        if total_gap > 0 || input.required_courses_completed == Some(false) {
+            ("불가", "공학교육인증 영역 학점 또는 인증필수 교과목 미충족".to_string())
+        } else if !missing_required.is_empty() {
+            (
+                "판정 불가",
+                format!("{} 인증 영역별 이수학점 기준이 코퍼스에 없음", row.label),
+            )
+        } else if !unverified.is_empty() {
+            ("판정 불가", format!("{} 확인 필요", unverified.join(", ")))
+        } else {
+            ("가능", "입력한 인증 기준을 모두 충족".to_string())
+        }
+    };
+    if input.required.iter().any(|(_, required)| required.is_some()) {
+        notes.push("required 값은 코퍼스가 아닌 입력값(accreditation_required)이므로 학과 인증 이수체계표와 대조 필요".to_string());
+    }
+
+    json!({
+        "judgement": judgement,
+        "applicable": matched == PartMatch::Full && row.active(),
+        "reason": reason,
+        "program_row": row.label,
+        "legacy_name": row.legacy_name,
+        "scheme": row.scheme,
+        "status": row.status,
+        "areas": areas,
+        "gap": gap,
+        "required_courses_completed": input.required_courses_completed,
+        "unverified": unverified,
+        "known_gap": known_gap,
+        "citation": row.citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> AccreditationInput {
+        AccreditationInput {
+            earned: AREAS.iter().map(|(_, label)| (*label, Some(30))).collect(),
+            required: AREAS.iter().map(|(_, label)| (*label, Some(30))).collect(),
+            required_courses_completed: Some(true),
+        }
+    }
+
+    #[test]
+    fn legacy_department_names_pick_their_own_row() {
+        let legacy = catalog::resolve("IT대학", "정보통신전자공학부", None);
+        assert_eq!(legacy.department, "전자정보공학부");
+        let result = evaluate_accreditation(&legacy, Some(2015), &input(), None);
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["status"], "2018.2.28. 종료");
+        assert_eq!(result["legacy_name"], "정보통신전자공학부");
+
+        let current = catalog::resolve("IT대학", "전자정보공학부", None);
+        let result = evaluate_accreditation(&current, Some(2015), &input(), None);
+        assert_eq!(result["status"], "2016.2.29. 종료");
+        assert_eq!(result["legacy_name"], Value::Null);
+
+        let renamed = catalog::resolve("공대", "신소재공학과", None);
+        let result = evaluate_accreditation(&renamed, None, &input(), None);
+        assert_eq!(result["program_row"], "신소재공학과");
+        assert_eq!(result["legacy_name"], "유기신소재·파이버공학과");
+    }
+
+    #[test]
+    fn unknown_departments_are_undecided_and_listed_ones_are_not_applicable() {
+        let unknown = catalog::resolve("공과대학", "우주항공공학과", None);
+        let result = evaluate_accreditation(&unknown, None, &input(), None);
+        assert_eq!(result["judgement"], "판정 불가");
+        assert_eq!(result["applicable"], false);
+
+        let missing = catalog::resolve("공과대학", "", None);
+        assert_eq!(evaluate_accreditation(&missing, None, &input(), None)["judgement"], "판정 불가");
+
+        let outside = catalog::resolve("경영대학", "경영학부", None);
+        let result = evaluate_accreditation(&outside, None, &input(), None);
+        assert_eq!(result["judgement"], "불가");
+        assert!(result["reason"].as_str().unwrap_or_default().starts_with("제5조의2 공학교육인증제 운영 학과(부)가 아님"));
+
+        let active = catalog::resolve("공과대학", "산업정보시스템공학과", None);
+        let result = evaluate_accreditation(&active, Some(2024), &input(), None);
+        assert_eq!(result["judgement"], "가능");
+        assert_eq!(result["applicable"], true);
+    }
+}
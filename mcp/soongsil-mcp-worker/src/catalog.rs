@@ -0,0 +1,397 @@
+use serde_json::{json, Value};
+
+use crate::table::{self, CorpusTable};
+use crate::PATH_PROGRAM_CATALOG;
+
+pub(crate) const CATALOG_CITATION: &str = "(학칙.pdf, p.53)";
+
+pub(crate) struct Department {
+    pub college: String,
+    pub name: String,
+    pub majors: Vec<String>,
+    aliases: Vec<String>,
+}
+
+pub(crate) struct Program {
+    pub college: String,
+    pub department: String,
+    pub major: Option<String>,
+    pub known: bool,
+    input_college: String,
+    input_department: String,
+    notes: Vec<String>,
+}
+
+impl Program {
+    pub(crate) fn label(&self) -> String {
+        match &self.major {
+            Some(major) => format!("{} {}", self.department, major),
+            None => self.department.clone(),
+        }
+    }
+
+    pub(crate) fn input_department(&self) -> &str {
+        &self.input_department
+    }
+
+    pub(crate) fn summary(&self) -> Value {
+        json!({
+            "input": { "college": self.input_college, "department": self.input_department },
+            "college": self.college,
+            "department": self.department,
+            "major": self.major,
+            "in_catalog": self.known,
+            "notes": self.notes,
+            "citation": CATALOG_CITATION,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum PartMatch {
+    Full,
+    Partial,
+    None,
+}
+
+pub(crate) enum Scope {
+    All,
+    Except(Vec<String>),
+    Listed(Vec<String>),
+}
+
+impl Scope {
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Scope::All => "전체",
+            Scope::Except(_) => "외",
+            Scope::Listed(_) => "명시",
+        }
+    }
+
+    pub(crate) fn best_match(&self, department: &str, major: Option<&str>) -> PartMatch {
+        let parts = match self {
+            Scope::All => return PartMatch::Full,
+            Scope::Except(parts) | Scope::Listed(parts) => parts,
+        };
+        let matches: Vec<PartMatch> = parts
+            .iter()
+            .map(|part| part_match(part, department, major))
+            .collect();
+        if matches.contains(&PartMatch::Full) {
+            PartMatch::Full
+        } else if matches.contains(&PartMatch::Partial) {
+            PartMatch::Partial
+        } else {
+            PartMatch::None
+        }
+    }
+
+    pub(crate) fn covers(&self, department: &str, major: Option<&str>) -> bool {
+        match self {
+            Scope::All => true,
+            Scope::Except(_) => self.best_match(department, major) != PartMatch::Full,
+            Scope::Listed(_) => self.best_match(department, major) == PartMatch::Full,
+        }
+    }
+}
+
+pub(crate) fn name_key(raw: &str) -> String {
+    raw.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '·' | '․' | '&'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn split_list(cell: &str) -> Vec<String> {
+    cell.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty() && *item != "-")
+        .map(str::to_string)
+        .collect()
+}
+
+fn split_parts(label: &str) -> Vec<String> {
+    label
+        .split('·')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+pub(crate) fn row_scope(label: &str) -> Scope {
+    let label = label.trim();
+    if label == "전체" {
+        Scope::All
+    } else if let Some(listed) = label.strip_suffix(" 외").or_else(|| label.strip_suffix('외')) {
+        Scope::Except(split_parts(listed))
+    } else {
+        Scope::Listed(split_parts(label))
+    }
+}
+
+fn part_match(part: &str, department: &str, major: Option<&str>) -> PartMatch {
+    let part_key = name_key(part);
+    let department_key = name_key(department);
+    if department_key.is_empty() {
+        return PartMatch::None;
+    }
+    if part_key == department_key {
+        return PartMatch::Full;
+    }
+    match major {
+        Some(major) if part_key == format!("{department_key}{}", name_key(major)) => PartMatch::Full,
+        None if part_key.starts_with(&department_key) => PartMatch::Partial,
+        _ => PartMatch::None,
+    }
+}
+
+pub(crate) fn catalog_table(section: &str, as_of: Option<&str>) -> Option<CorpusTable> {
+    table::extract_tables(PATH_PROGRAM_CATALOG, as_of)
+        .into_iter()
+        .find(|table| table.section == section)
+}
+
+fn colleges(as_of: Option<&str>) -> Vec<(String, Vec<String>)> {
+    let Some(table) = catalog_table("Colleges", as_of) else {
+        return vec![];
+    };
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let name = table.cell(row, "대학")?.to_string();
+            let aliases = split_list(table.cell(row, "별칭").unwrap_or_default());
+            Some((name, aliases))
+        })
+        .collect()
+}
+
+pub(crate) fn departments(as_of: Option<&str>) -> Vec<Department> {
+    let Some(table) = catalog_table("Departments", as_of) else {
+        return vec![];
+    };
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(Department {
+                college: table.cell(row, "대학")?.to_string(),
+                name: table.cell(row, "학과/학부")?.to_string(),
+                majors: split_list(table.cell(row, "전공").unwrap_or_default()),
+                aliases: split_list(table.cell(row, "별칭").unwrap_or_default()),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn canonical_college(raw: &str, as_of: Option<&str>) -> String {
+    let key = name_key(raw);
+    if key.is_empty() {
+        return String::new();
+    }
+    colleges(as_of)
+        .into_iter()
+        .find(|(name, aliases)| name_key(name) == key || aliases.iter().any(|alias| name_key(alias) == key))
+        .map(|(name, _)| name)
+        .unwrap_or_else(|| raw.trim().to_string())
+}
+
+fn major_matches(key: &str, department: &Department, major: &str) -> bool {
+    let major_key = name_key(major);
+    let short_key = major_key.strip_suffix("전공").unwrap_or(&major_key);
+    let department_key = name_key(&department.name);
+    key == major_key
+        || key == short_key
+        || key == format!("{department_key}{major_key}")
+        || key == format!("{department_key}{short_key}")
+}
+
+fn find_department<'a>(
+    departments: &'a [Department],
+    college: &str,
+    key: &str,
+) -> Option<(&'a Department, Option<String>)> {
+    let mut found: Vec<(&Department, Option<String>)> = departments
+        .iter()
+        .filter(|department| {
+            name_key(&department.name) == key || department.aliases.iter().any(|alias| name_key(alias) == key)
+        })
+        .map(|department| (department, None))
+        .collect();
+    if found.is_empty() {
+        found = departments
+            .iter()
+            .flat_map(|department| {
+                department
+                    .majors
+                    .iter()
+                    .filter(|major| major_matches(key, department, major))
+                    .map(move |major| (department, Some(major.clone())))
+            })
+            .collect();
+    }
+    let own_college = found.iter().position(|(department, _)| department.college == college);
+    match own_college {
+        Some(idx) => Some(found.swap_remove(idx)),
+        None => found.into_iter().next(),
+    }
+}
+
+pub(crate) fn resolve(college: &str, department: &str, as_of: Option<&str>) -> Program {
+    let departments = departments(as_of);
+    let college_name = canonical_college(college, as_of);
+    let department_key = name_key(department);
+    let mut notes = Vec::new();
+    if !college_name.is_empty() && college_name != college.trim() {
+        notes.push(format!("대학 '{}' → '{college_name}'", college.trim()));
+    }
+
+    let found = if department_key.is_empty() {
+        departments
+            .iter()
+            .find(|entry| entry.college == college_name && entry.name == college_name)
+            .map(|entry| (entry, None))
+    } else {
+        find_department(&departments, &college_name, &department_key)
+    };
+
+    match found {
+        Some((entry, major)) => {
+            let resolved = match &major {
+                Some(major) => format!("{} {major}", entry.name),
+                None => entry.name.clone(),
+            };
+            if !department_key.is_empty() && name_key(&resolved) != department_key {
+                notes.push(format!("학과 '{}' → '{resolved}'", department.trim()));
+            }
+            if college_name.is_empty() {
+                notes.push(format!("대학 미입력: 학과(부) 소속 대학 '{}'으로 보완", entry.college));
+            } else if college_name != entry.college {
+                notes.push(format!(
+                    "입력 대학 '{college_name}'과 학과(부) 소속 대학 '{}'이 달라 카탈로그 소속 대학 기준으로 매칭",
+                    entry.college
+                ));
+            }
+            Program {
+                college: entry.college.clone(),
+                department: entry.name.clone(),
+                major,
+                known: true,
+                input_college: college.trim().to_string(),
+                input_department: department.trim().to_string(),
+                notes,
+            }
+        }
+        None => {
+            if !department_key.is_empty() {
+                notes.push(format!(
+                    "학과 '{}'가 프로그램 카탈로그에 없어 입력값 그대로 학점 이수 체계 행과 비교",
+                    department.trim()
+                ));
+            }
+            Program {
+                college: college_name,
+                department: department.trim().to_string(),
+                major: None,
+                known: false,
+                input_college: college.trim().to_string(),
+                input_department: department.trim().to_string(),
+                notes,
+            }
+        }
+    }
+}
+
+pub(crate) fn covered_programs(departments: &[Department], college: &str, scope: &Scope) -> Vec<String> {
+    let mut covered = Vec::new();
+    for department in departments.iter().filter(|department| department.college == college) {
+        if department.majors.is_empty() {
+            if scope.covers(&department.name, None) {
+                covered.push(department.name.clone());
+            }
+            continue;
+        }
+        let majors: Vec<&String> = department
+            .majors
+            .iter()
+            .filter(|major| scope.covers(&department.name, Some(major)))
+            .collect();
+        if majors.len() == department.majors.len() {
+            covered.push(department.name.clone());
+        } else {
+            covered.extend(majors.into_iter().map(|major| format!("{} {major}", department.name)));
+        }
+    }
+    covered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colleges_resolve_by_name_or_alias() {
+        assert_eq!(canonical_college("경통대", None), "경제통상대학");
+        assert_eq!(canonical_college(" it대 ", None), "IT대학");
+        assert_eq!(canonical_college("IT대학", None), "IT대학");
+        assert_eq!(canonical_college("미래대학", None), "미래대학");
+        assert_eq!(canonical_college("  ", None), "");
+    }
+
+    #[test]
+    fn departments_resolve_through_aliases_and_majors() {
+        let program = resolve("", "컴공", None);
+        assert!(program.known);
+        assert_eq!((program.college.as_str(), program.department.as_str()), ("IT대학", "컴퓨터학부"));
+        assert!(program.summary()["notes"][1].as_str().unwrap_or_default().starts_with("대학 미입력"));
+
+        let program = resolve("공대", "건축학전공", None);
+        assert_eq!(program.label(), "건축학부 건축학전공");
+        assert_eq!(program.major.as_deref(), Some("건축학전공"));
+
+        let program = resolve("인문대", "경영학부", None);
+        assert_eq!(program.college, "경영대학");
+        assert!(program.summary()["notes"][1]
+            .as_str()
+            .unwrap_or_default()
+            .contains("카탈로그 소속 대학 기준"));
+
+        let program = resolve("공대", "유기신소재·파이버공학과", None);
+        assert_eq!(program.department, "신소재공학과");
+        assert_eq!(program.input_department(), "유기신소재·파이버공학과");
+    }
+
+    #[test]
+    fn unknown_departments_keep_the_input() {
+        let program = resolve("IT대", "우주항공학과", None);
+        assert!(!program.known);
+        assert_eq!((program.college.as_str(), program.department.as_str()), ("IT대학", "우주항공학과"));
+        assert!(program.summary()["notes"][1]
+            .as_str()
+            .unwrap_or_default()
+            .contains("카탈로그에 없어"));
+    }
+
+    #[test]
+    fn row_labels_become_scopes() {
+        assert!(matches!(row_scope("전체"), Scope::All));
+        assert!(row_scope("전체").covers("아무학과", None));
+
+        let except = row_scope("독어독문학과·예술창작학부 영화예술전공·스포츠학부 외");
+        assert_eq!(except.kind(), "외");
+        assert!(!except.covers("스포츠학부", None));
+        assert!(!except.covers("예술창작학부", Some("영화예술전공")));
+        assert!(except.covers("예술창작학부", Some("문예창작전공")));
+        assert!(except.covers("국어국문학과", None));
+        assert!(matches!(row_scope("금융학부외"), Scope::Except(parts) if parts == ["금융학부"]));
+
+        let listed = row_scope("건축학부 건축학전공");
+        assert_eq!(listed.kind(), "명시");
+        assert!(listed.best_match("건축학부", None) == PartMatch::Partial);
+        assert!(listed.covers("건축학부", Some("건축학전공")));
+        assert!(!listed.covers("건축학부", Some("실내건축전공")));
+        assert!(!listed.covers("건축학부", None));
+    }
+}
@@ -0,0 +1,163 @@
+use serde_json::{json, Map, Value};
+
+use crate::catalog::{name_key, Program};
+use crate::{article_citation, extract_first_int, table, PATH_LAW_NUMERIC};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Met,
+    Unmet,
+    Unknown,
+    NotApplicable,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Met => "met",
+            Status::Unmet => "unmet",
+            Status::Unknown => "unknown",
+            Status::NotApplicable => "not_applicable",
+        }
+    }
+}
+
+fn input_status(args: &Map<String, Value>, key: &str) -> Option<bool> {
+    match args.get(key)? {
+        Value::Bool(flag) => Some(*flag),
+        Value::String(text) => Some(!text.trim().is_empty()),
+        _ => None,
+    }
+}
+
+fn applies(
+    target: &str,
+    args: &Map<String, Value>,
+    program: &Program,
+    accreditation: Option<&Value>,
+) -> Option<bool> {
+    match target {
+        "전체" => Some(true),
+        "외국인학생" => args.get("international_student").and_then(Value::as_bool),
+        "공학교육심화과정 참여자" => Some(accreditation.is_some_and(|evaluated| evaluated["applicable"] == true)),
+        _ => {
+            let key = name_key(target);
+            Some(name_key(&program.department) == key || name_key(&program.college) == key)
+        }
+    }
+}
+
+fn item_status(
+    inputs: &[&str],
+    requirement: &str,
+    args: &Map<String, Value>,
+    accreditation: Option<&Value>,
+) -> Status {
+    let answers: Vec<Option<bool>> = inputs
+        .iter()
+        .map(|input| match *input {
+            "topik_level" => {
+                let level = args.get("topik_level").and_then(Value::as_i64)?;
+                Some(level >= extract_first_int(requirement).unwrap_or(4))
+            }
+            "accreditation" => match accreditation?["judgement"].as_str() {
+                Some("가능") => Some(true),
+                Some("불가") => Some(false),
+                _ => None,
+            },
+            key => input_status(args, key),
+        })
+        .collect();
+    if answers.contains(&Some(true)) {
+        Status::Met
+    } else if answers.iter().all(|answer| *answer == Some(false)) {
+        Status::Unmet
+    } else {
+        Status::Unknown
+    }
+}
+
+pub(crate) fn evaluate_checklist(
+    args: &Map<String, Value>,
+    program: &Program,
+    accreditation: Option<&Value>,
+    as_of: Option<&str>,
+) -> Value {
+    let Some(table) = table::find_table(PATH_LAW_NUMERIC, as_of, &["항목", "요건", "대상", "입력"]) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 학점 외 졸업요건 표를 찾지 못함",
+            "items": [],
+            "citation": "(학칙.pdf, p.11, 제46조 1항)"
+        });
+    };
+
+    let mut unmet = Vec::new();
+    let mut unknown = Vec::new();
+    let items: Vec<Value> = table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let name = table.cell(row, "항목")?;
+            let requirement = table.cell(row, "요건").unwrap_or_default();
+            let target = table.cell(row, "대상").unwrap_or("전체");
+            let inputs: Vec<&str> = table
+                .cell(row, "입력")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|input| !input.is_empty())
+                .collect();
+            let status = match applies(target, args, program, accreditation) {
+                Some(false) => Status::NotApplicable,
+                None => Status::Unknown,
+                Some(true) => item_status(&inputs, requirement, args, accreditation),
+            };
+            match status {
+                Status::Unmet => unmet.push(name.to_string()),
+                Status::Unknown => unknown.push(name.to_string()),
+                _ => {}
+            }
+            Some(json!({
+                "item": name,
+                "requirement": requirement,
+                "applies_to": target,
+                "status": status.label(),
+                "inputs": inputs,
+                "citation": article_citation(table.cell(row, "근거").unwrap_or_default()),
+            }))
+        })
+        .collect();
+
+    let (judgement, reason) = if !unmet.is_empty() {
+        ("불가", format!("미충족: {}", unmet.join(", ")))
+    } else if !unknown.is_empty() {
+        ("판정 불가", format!("확인 필요: {}", unknown.join(", ")))
+    } else {
+        ("가능", "적용 대상 학점 외 요건 모두 충족".to_string())
+    };
+
+    let mut notes = vec![
+        "졸업논문/졸업종합시험 대체 기준은 시행세칙 소관 (학칙.pdf, p.11, 제46조 3항)".to_string(),
+        "졸업은 졸업학기 종료일 전 소정기간까지 졸업확정신고를 해야 인정 (학칙.pdf, p.11, 제46조 1항)".to_string(),
+    ];
+    if args.get("international_student").is_none() {
+        notes.push("international_student 미입력: 외국인 TOPIK 요건 적용 여부를 알 수 없음".to_string());
+    }
+    if accreditation.is_none() {
+        notes.push("accreditation 미입력: 공학교육심화과정 비참여자로 가정".to_string());
+    }
+    if args.get("international_student").and_then(Value::as_bool) == Some(true) {
+        notes.push("TOPIK 응시 불가 사유가 있으면 총장이 정한 방식으로 대체 인정 가능 (학칙.pdf, p.11, 제46조 2항)".to_string());
+    }
+
+    json!({
+        "judgement": judgement,
+        "reason": reason,
+        "items": items,
+        "unmet": unmet,
+        "unknown": unknown,
+        "citation": "(학칙.pdf, p.11, 제46조 1항)",
+        "notes": notes,
+    })
+}
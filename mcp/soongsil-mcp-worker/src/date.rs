@@ -0,0 +1,96 @@
+const KST_OFFSET_MILLIS: i64 = 9 * 3_600_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CivilDate {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+}
+
+impl CivilDate {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.trim().splitn(3, '-');
+        let year = parts.next()?.parse::<i64>().ok()?;
+        let month = parts.next()?.parse::<i64>().ok()?;
+        let day = parts.next()?.parse::<i64>().ok()?;
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    pub(crate) fn parse_iso(value: &str) -> Option<Self> {
+        Self::parse(value).filter(|date| date.iso() == value)
+    }
+
+    pub(crate) fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self { year, month, day }
+    }
+
+    pub(crate) fn to_days(self) -> i64 {
+        let year = if self.month <= 2 { self.year - 1 } else { self.year };
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let mp = (self.month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + self.day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    pub(crate) fn days_until(self, other: Self) -> i64 {
+        other.to_days() - self.to_days()
+    }
+
+    pub(crate) fn iso(self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_millis() -> i64 {
+    worker::Date::now().as_millis() as i64
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+pub(crate) fn today() -> CivilDate {
+    CivilDate::from_days((now_millis() + KST_OFFSET_MILLIS).div_euclid(86_400_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_iso_requires_the_padded_form() {
+        assert_eq!(CivilDate::parse_iso("2024-02-29").map(CivilDate::iso).as_deref(), Some("2024-02-29"));
+        assert!(CivilDate::parse("2024-3-1").is_some());
+        assert!(CivilDate::parse_iso("2024-3-1").is_none());
+        assert!(CivilDate::parse_iso("2023-02-29").is_none());
+        assert!(CivilDate::parse_iso(" 2024-03-01").is_none());
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, HashSet};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::table::extract_tables_from_text;
+use crate::{citation_hint, parse_page_heading, Snapshot};
+
+static ARTICLE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(제\d+조(?:의\d+)?)\s*\(([^)]*)\)").expect("ARTICLE_RE compile failure")
+});
+static PAGE_MARK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\d+-\d+-\d+～\d+$").expect("PAGE_MARK_RE compile failure"));
+
+struct Article {
+    title: String,
+    page: Option<String>,
+    body: String,
+}
+
+struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+fn collect_articles(text: &str) -> BTreeMap<String, Article> {
+    let mut articles: BTreeMap<String, Article> = BTreeMap::new();
+    let mut current: Option<String> = None;
+    let mut page: Option<String> = None;
+
+    for line in text.lines() {
+        let stripped = line.trim();
+        if let Some(p) = parse_page_heading(stripped) {
+            page = Some(p);
+            continue;
+        }
+        if stripped.is_empty()
+            || stripped.starts_with('#')
+            || stripped.starts_with("```")
+            || stripped.starts_with('|')
+            || PAGE_MARK_RE.is_match(stripped)
+        {
+            continue;
+        }
+
+        if let Some(captures) = ARTICLE_RE.captures(stripped) {
+            let key = captures[1].to_string();
+            if !articles.contains_key(&key) {
+                articles.insert(
+                    key.clone(),
+                    Article {
+                        title: captures[2].to_string(),
+                        page: page.clone(),
+                        body: String::new(),
+                    },
+                );
+                current = Some(key);
+            } else {
+                current = None;
+            }
+        }
+
+        if let Some(article) = current.as_ref().and_then(|key| articles.get_mut(key)) {
+            article.body.push_str(stripped);
+        }
+    }
+
+    articles
+}
+
+fn collect_tables(snapshot: &Snapshot) -> BTreeMap<String, Table> {
+    let mut tables: BTreeMap<String, Table> = BTreeMap::new();
+    for table in extract_tables_from_text(snapshot.path, snapshot.text) {
+        let heading = if table.section.is_empty() {
+            table
+                .page
+                .as_ref()
+                .map(|page| format!("p.{page}"))
+                .unwrap_or_default()
+        } else {
+            table.section.clone()
+        };
+        let mut name = heading.clone();
+        let mut suffix = 2;
+        while tables.contains_key(&name) {
+            name = format!("{heading} #{suffix}");
+            suffix += 1;
+        }
+        tables.insert(
+            name,
+            Table {
+                headers: table.headers,
+                rows: table.rows.into_iter().map(|row| row.cells).collect(),
+            },
+        );
+    }
+    tables
+}
+
+fn key_width(tables: &[&Table]) -> usize {
+    let max_width = tables
+        .iter()
+        .flat_map(|table| table.rows.iter().map(Vec::len))
+        .max()
+        .unwrap_or(1);
+    (1..=max_width)
+        .find(|width| {
+            tables.iter().all(|table| {
+                let mut seen = HashSet::new();
+                table
+                    .rows
+                    .iter()
+                    .all(|row| seen.insert(row.iter().take(*width).cloned().collect::<Vec<_>>()))
+            })
+        })
+        .unwrap_or(max_width)
+}
+
+fn keyed_rows(table: &Table, width: usize) -> BTreeMap<String, &Vec<String>> {
+    let mut keyed = BTreeMap::new();
+    for row in &table.rows {
+        let base = row
+            .iter()
+            .take(width)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let mut key = base.clone();
+        let mut suffix = 2;
+        while keyed.contains_key(&key) {
+            key = format!("{base} #{suffix}");
+            suffix += 1;
+        }
+        keyed.insert(key, row);
+    }
+    keyed
+}
+
+fn row_object(headers: &[String], row: &[String]) -> Value {
+    let mut object = serde_json::Map::new();
+    for (idx, value) in row.iter().enumerate() {
+        let column = headers
+            .get(idx)
+            .cloned()
+            .unwrap_or_else(|| format!("column_{}", idx + 1));
+        object.insert(column, Value::String(value.clone()));
+    }
+    Value::Object(object)
+}
+
+fn diff_rows(base: &Table, target: &Table) -> (Vec<Value>, Vec<Value>, Vec<Value>) {
+    let width = key_width(&[base, target]);
+    let before = keyed_rows(base, width);
+    let after = keyed_rows(target, width);
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+
+    for (key, row) in &before {
+        let Some(next) = after.get(key) else {
+            removed.push(json!({ "key": key, "row": row_object(&base.headers, row) }));
+            continue;
+        };
+
+        let mut columns: Vec<String> = base.headers.clone();
+        for header in &target.headers {
+            if !columns.contains(header) {
+                columns.push(header.clone());
+            }
+        }
+
+        let changes: Vec<Value> = columns
+            .iter()
+            .filter_map(|column| {
+                let old = base
+                    .headers
+                    .iter()
+                    .position(|h| h == column)
+                    .and_then(|i| row.get(i));
+                let new = target
+                    .headers
+                    .iter()
+                    .position(|h| h == column)
+                    .and_then(|i| next.get(i));
+                (old != new).then(|| json!({ "column": column, "from": old, "to": new }))
+            })
+            .collect();
+
+        if !changes.is_empty() {
+            changed.push(json!({ "key": key, "changes": changes }));
+        }
+    }
+
+    for (key, row) in &after {
+        if !before.contains_key(key) {
+            added.push(json!({ "key": key, "row": row_object(&target.headers, row) }));
+        }
+    }
+
+    (added, removed, changed)
+}
+
+fn article_citation(path: &str, article: &Article) -> String {
+    citation_hint(path, article.page.as_deref())
+}
+
+pub(crate) fn diff_snapshots(base: &Snapshot, target: &Snapshot) -> Value {
+    let before_articles = collect_articles(base.text);
+    let after_articles = collect_articles(target.text);
+    let mut articles_added = Vec::new();
+    let mut articles_removed = Vec::new();
+    let mut articles_changed = Vec::new();
+
+    for (key, article) in &before_articles {
+        match after_articles.get(key) {
+            None => articles_removed.push(json!({
+                "article": key,
+                "title": article.title,
+                "citation": article_citation(base.path, article),
+            })),
+            Some(next) if next.title != article.title || next.body != article.body => {
+                articles_changed.push(json!({
+                    "article": key,
+                    "title": { "from": article.title, "to": next.title },
+                    "from": article.body.chars().take(240).collect::<String>(),
+                    "to": next.body.chars().take(240).collect::<String>(),
+                    "citation": article_citation(target.path, next),
+                }))
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, article) in &after_articles {
+        if !before_articles.contains_key(key) {
+            articles_added.push(json!({
+                "article": key,
+                "title": article.title,
+                "citation": article_citation(target.path, article),
+            }));
+        }
+    }
+
+    let before_tables = collect_tables(base);
+    let after_tables = collect_tables(target);
+    let mut tables = Vec::new();
+    let mut rows_added = 0;
+    let mut rows_removed = 0;
+    let mut rows_changed = 0;
+
+    for (name, table) in &before_tables {
+        let (added, removed, changed) = match after_tables.get(name) {
+            Some(next) => diff_rows(table, next),
+            None => diff_rows(table, &Table { headers: table.headers.clone(), rows: vec![] }),
+        };
+        if added.is_empty() && removed.is_empty() && changed.is_empty() {
+            continue;
+        }
+        rows_added += added.len();
+        rows_removed += removed.len();
+        rows_changed += changed.len();
+        tables.push(json!({
+            "table": name,
+            "status": if after_tables.contains_key(name) { "changed" } else { "removed" },
+            "added": added,
+            "removed": removed,
+            "changed": changed,
+        }));
+    }
+    for (name, table) in &after_tables {
+        if before_tables.contains_key(name) {
+            continue;
+        }
+        let (added, _, _) = diff_rows(&Table { headers: table.headers.clone(), rows: vec![] }, table);
+        rows_added += added.len();
+        tables.push(json!({
+            "table": name,
+            "status": "added",
+            "added": added,
+            "removed": [],
+            "changed": [],
+        }));
+    }
+
+    let identical = articles_added.is_empty()
+        && articles_removed.is_empty()
+        && articles_changed.is_empty()
+        && tables.is_empty();
+
+    json!({
+        "file": base.path,
+        "from": base.effective_from,
+        "to": target.effective_from,
+        "identical": identical,
+        "summary": {
+            "articles_added": articles_added.len(),
+            "articles_removed": articles_removed.len(),
+            "articles_changed": articles_changed.len(),
+            "rows_added": rows_added,
+            "rows_removed": rows_removed,
+            "rows_changed": rows_changed,
+        },
+        "articles": {
+            "added": articles_added,
+            "removed": articles_removed,
+            "changed": articles_changed,
+        },
+        "tables": tables,
+        "notes": [
+            "조문 단위 비교는 '제N조(표제)'로 시작하는 본문 라인 기준",
+            "표 행 비교는 표 제목(직전 heading) 기준으로 같은 표를 맞추고, 앞쪽 컬럼 조합을 행 키로 사용",
+            "최종 개정 내용은 각 시점의 원본 PDF로 재확인 필요"
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: Snapshot = Snapshot {
+        path: "/knowledge/normalized-md/학칙.md",
+        effective_from: "2025-03-01",
+        text: "# 학칙\n\n## p.1\n\n제1조(목적) 이 학칙은 교육에 관한 사항을 규정한다.\n제2조(수업연한) 수업연한은 4년으로 한다.\n\n### 모집단위\n\n| 학과 | 정원 |\n| --- | --- |\n| 컴퓨터학부 | 100 |\n| 기계공학부 | 80 |\n",
+    };
+    const TARGET: Snapshot = Snapshot {
+        path: "/knowledge/normalized-md/학칙.md",
+        effective_from: "2026-02-16",
+        text: "# 학칙\n\n## p.1\n\n제1조(목적) 이 학칙은 교육에 관한 사항을 규정한다.\n제2조(수업연한) 수업연한은 4년으로 한다. 다만, 건축학전공은 5년으로 한다.\n\n## p.2\n\n제3조(학년도) 학년도는 3월 1일부터 다음 해 2월 말일까지로 한다.\n\n### 모집단위\n\n| 학과 | 정원 |\n| --- | --- |\n| 컴퓨터학부 | 100 |\n| 기계공학부 | 90 |\n| 소프트웨어학부 | 120 |\n",
+    };
+
+    #[test]
+    fn reports_article_and_row_changes_between_revisions() {
+        let diff = diff_snapshots(&BASE, &TARGET);
+        assert_eq!(diff["identical"], false);
+        assert_eq!(diff["from"], "2025-03-01");
+        assert_eq!(diff["to"], "2026-02-16");
+        assert_eq!(diff["summary"]["articles_added"], 1);
+        assert_eq!(diff["summary"]["articles_changed"], 1);
+        assert_eq!(diff["summary"]["articles_removed"], 0);
+        assert_eq!(diff["articles"]["added"][0]["article"], "제3조");
+        assert_eq!(diff["articles"]["added"][0]["citation"], "(학칙.pdf, p.2)");
+        assert_eq!(diff["articles"]["changed"][0]["article"], "제2조");
+        assert_eq!(diff["summary"]["rows_added"], 1);
+        assert_eq!(diff["summary"]["rows_changed"], 1);
+        let changed = &diff["tables"][0]["changed"][0];
+        assert_eq!(changed["key"], "기계공학부");
+        assert_eq!(changed["changes"][0]["from"], "80");
+        assert_eq!(changed["changes"][0]["to"], "90");
+    }
+
+    #[test]
+    fn same_snapshot_is_identical() {
+        let diff = diff_snapshots(&TARGET, &TARGET);
+        assert_eq!(diff["identical"], true);
+        assert_eq!(diff["tables"], json!([]));
+    }
+}
@@ -0,0 +1,219 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::gpa::{format_gpa, round2};
+use crate::{catalog, evaluate_credit_totals, extract_first_int, numeric_rule, standing, CreditTotals};
+
+static DECIMAL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\d+(?:\.\d+)?").expect("DECIMAL_RE compile failure"));
+static SEMESTER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"제?(\d+)학기").expect("SEMESTER_RE compile failure"));
+
+pub(crate) struct EarlyRequest<'a> {
+    pub college: &'a str,
+    pub department: &'a str,
+    pub major_type: &'a str,
+    pub gpa: f64,
+    pub registered_semesters: i64,
+    pub earned_total: i64,
+    pub categories: Option<CreditTotals>,
+    pub admission_year: Option<i64>,
+    pub semester_cap: Option<i64>,
+    pub requirements: &'a Value,
+}
+
+pub(crate) fn extract_first_decimal(value: &str) -> Option<f64> {
+    DECIMAL_RE.find(value)?.as_str().parse().ok()
+}
+
+fn semester_window(as_of: Option<&str>) -> Option<(i64, i64, String)> {
+    let (value, citation) = numeric_rule("조기졸업 등록학기", as_of)?;
+    let semesters: Vec<i64> = SEMESTER_RE
+        .captures_iter(&value)
+        .filter_map(|caps| caps[1].parse().ok())
+        .collect();
+    Some((*semesters.first()?, *semesters.last()?, citation))
+}
+
+fn condition(name: &str, required: Value, actual: Value, met: Option<bool>, citation: &str) -> Value {
+    json!({
+        "condition": name,
+        "required": required,
+        "actual": actual,
+        "met": met,
+        "citation": citation,
+    })
+}
+
+pub(crate) fn evaluate_early_graduation(request: &EarlyRequest, as_of: Option<&str>) -> Value {
+    let (Some((min_gpa, gpa_citation)), Some((first, last, window_citation)), Some((annual_cap, cap_citation))) = (
+        numeric_rule("조기졸업 평점", as_of)
+            .and_then(|(value, citation)| Some((extract_first_decimal(&value)?, citation))),
+        semester_window(as_of),
+        numeric_rule("학사 연간 이수학점", as_of)
+            .and_then(|(value, citation)| Some((extract_first_int(&value)?, citation))),
+    ) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 조기졸업 평점/등록학기 또는 연간 이수학점 기준을 찾지 못함",
+            "citation": "(학칙.pdf, p.11, 제46조 4항)"
+        });
+    };
+
+    let zero = CreditTotals {
+        liberal_required: 0,
+        liberal_elective: 0,
+        major_basic: 0,
+        major: 0,
+        total: request.earned_total,
+    };
+    let audit = evaluate_credit_totals(
+        request.college,
+        request.department,
+        request.major_type,
+        request.categories.as_ref().unwrap_or(&zero),
+        request.admission_year,
+        as_of,
+    );
+    let Some(required_total) = audit["required"]["졸업학점"].as_i64() else {
+        return json!({
+            "judgement": audit["judgement"].clone(),
+            "reason": "졸업요건 행을 확정하지 못해 조기졸업 학점 조건을 계산할 수 없음",
+            "audit": audit,
+            "citation": window_citation
+        });
+    };
+    let credit_citation = audit["citation"].as_str().unwrap_or("(학점 이수 체계.pdf, p.1)").to_string();
+
+    let semesters = request.registered_semesters;
+    let remaining_credits = (required_total - request.earned_total).max(0);
+    let per_semester = request.semester_cap.unwrap_or(annual_cap / 2).max(1);
+    let earliest = (first.max(semesters)..=last)
+        .find(|target| remaining_credits <= (target - semesters) * per_semester);
+
+    let category_gap: Option<i64> = request.categories.as_ref().map(|_| {
+        audit["gap"]
+            .as_object()
+            .map(|gap| {
+                gap.iter()
+                    .filter(|(key, _)| key.as_str() != "졸업학점")
+                    .filter_map(|(_, value)| value.as_i64())
+                    .sum()
+            })
+            .unwrap_or(0)
+    });
+
+    let gpa_met = request.gpa >= min_gpa;
+    let requirements_met = match request.requirements["judgement"].as_str() {
+        Some("가능") => Some(true),
+        Some("불가") => Some(false),
+        _ => None,
+    };
+    let window_met = if semesters > last {
+        Some(false)
+    } else if semesters >= first {
+        Some(true)
+    } else {
+        None
+    };
+    let conditions = vec![
+        condition("평균평점", json!(min_gpa), json!(round2(request.gpa)), Some(gpa_met), &gpa_citation),
+        condition(
+            "등록 학기",
+            json!(format!("제{first}학기 ~ 제{last}학기")),
+            json!(semesters),
+            window_met,
+            &window_citation,
+        ),
+        condition(
+            "졸업학점",
+            json!(required_total),
+            json!(request.earned_total),
+            Some(remaining_credits == 0),
+            &credit_citation,
+        ),
+        condition(
+            "이수구분별 학점",
+            json!(audit["required"].clone()),
+            json!(request.categories.as_ref().map(|_| audit["earned"].clone())),
+            category_gap.map(|gap| gap == 0),
+            &credit_citation,
+        ),
+        condition(
+            "학점 외 졸업요건(졸업논문/졸업종합시험 등)",
+            json!("제45조·제46조 1항 각 호"),
+            request.requirements["reason"].clone(),
+            requirements_met,
+            "(학칙.pdf, p.11, 제46조 1항)",
+        ),
+    ];
+
+    let mut unverified = Vec::new();
+    if category_gap.is_none() {
+        unverified.push("이수구분별 학점");
+    }
+    if let Some(items) = audit["unverified"].as_array() {
+        unverified.extend(items.iter().filter_map(Value::as_str));
+    }
+    if requirements_met.is_none() {
+        match request.requirements["unknown"].as_array() {
+            Some(items) if !items.is_empty() => unverified.extend(items.iter().filter_map(Value::as_str)),
+            _ => unverified.push("학점 외 졸업요건"),
+        }
+    }
+
+    let (judgement, reason) = if window_met == Some(false) {
+        ("불가", format!("이미 제{last}학기를 넘겨 등록해 조기졸업 대상 학기가 지남"))
+    } else if !gpa_met {
+        ("불가", format!("현재 평균평점 {}이 조기졸업 기준 {min_gpa:.2} 미만", format_gpa(request.gpa)))
+    } else if earliest.is_none() {
+        (
+            "불가",
+            format!("제{last}학기까지 남은 학기에 학기당 {per_semester}학점으로 {remaining_credits}학점을 채울 수 없음"),
+        )
+    } else if category_gap.unwrap_or(0) > 0 && window_met == Some(true) && remaining_credits == 0 {
+        ("불가", "졸업학점은 채웠지만 이수구분별 학점이 부족".to_string())
+    } else if requirements_met == Some(false) {
+        (
+            "불가",
+            format!(
+                "학점 외 졸업요건(제45조·제46조) {}",
+                request.requirements["reason"].as_str().unwrap_or_default()
+            ),
+        )
+    } else if !unverified.is_empty() {
+        ("판정 불가", format!("{} 확인 필요", unverified.join(", ")))
+    } else {
+        ("가능", "평점·등록학기·학점 조건 충족 가능".to_string())
+    };
+
+    let five_year = standing::five_year_program(&catalog::resolve(request.college, request.department, as_of), as_of);
+    let mut notes = vec![
+        "조기졸업 시 수업연한 1년 단축 (학칙.pdf, p.4, 제16조 3항 1호)".to_string(),
+        format!("남은 학기 학점은 학기당 {per_semester}학점 기준(연간 {annual_cap}학점 한도 {cap_citation}, semester_cap으로 변경 가능)"),
+        "조기졸업 신청 절차와 세부 기준은 시행세칙 소관으로 코퍼스에 없음".to_string(),
+    ];
+    if five_year == Some(true) {
+        notes.push("건축학전공(5년제)도 제46조 4항의 제6~7학기 기준으로 계산: 시행세칙 확인 필요".to_string());
+    }
+    if request.categories.is_none() {
+        notes.push("earned_liberal_required 등 이수구분별 학점을 주면 졸업요건 행 전체와 비교".to_string());
+    }
+
+    json!({
+        "judgement": judgement,
+        "reason": reason,
+        "earliest_semester": earliest.filter(|_| judgement != "불가").map(|semester| format!("제{semester}학기")),
+        "remaining_credits": remaining_credits,
+        "per_semester_credits": per_semester,
+        "gpa_raw": request.gpa,
+        "conditions": conditions,
+        "unverified": unverified,
+        "requirements": request.requirements,
+        "program": audit["program"].clone(),
+        "row_match": audit["row_match"].clone(),
+        "citation": gpa_citation,
+        "notes": notes,
+    })
+}
@@ -0,0 +1,553 @@
+use std::collections::BTreeSet;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::catalog;
+use crate::liberal::cohort_range;
+use crate::table::{self, CorpusTable};
+use crate::transcript::course_key;
+use crate::{get_file_text, judgement_rank, optional_i64, optional_string, required_string, PATH_LIBERAL_ELECTIVE};
+
+static AREA_COUNT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d+)개 영역").expect("AREA_COUNT_RE compile failure"));
+static TOTAL_COURSES_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"총\s*(\d+)과목").expect("TOTAL_COURSES_RE compile failure"));
+static COURSES_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d+)과목").expect("COURSES_RE compile failure"));
+static CHOICE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{4})학년도 이전 입학자는 .*?(\d{4})(?:~\d{4})?학년도 이수체계 중 선택")
+        .expect("CHOICE_RE compile failure")
+});
+
+const GROUP_COLUMNS: &[&str] = &["교과구분", "역량군", "구분"];
+const AREA_COLUMNS: &[&str] = &["영역", "영역분류", "분야"];
+const CONDITION_COLUMNS: &[&str] = &["최소 이수요건", "이수조건"];
+
+pub(crate) struct ElectiveCourse {
+    pub name: String,
+    pub area: String,
+    pub credits: Option<i64>,
+    pub offering_college: Option<String>,
+}
+
+struct AreaGroup {
+    name: String,
+    areas: Vec<String>,
+    condition: String,
+    min_courses: usize,
+    min_areas: usize,
+    exclude_own_college: bool,
+    outside_own_field: bool,
+}
+
+pub(crate) struct ElectiveChoice {
+    pub until: i64,
+    pub alternative_year: i64,
+    pub note: String,
+    pub citation: String,
+}
+
+pub(crate) struct ElectiveSystem {
+    pub section: String,
+    pub cohort: (i64, i64),
+    table: CorpusTable,
+    groups: Vec<AreaGroup>,
+}
+
+impl ElectiveSystem {
+    pub(crate) fn is_balance(&self) -> bool {
+        self.groups.iter().any(|group| group.name == "BALANCE")
+    }
+}
+
+fn compact(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn captured_count(re: &Regex, text: &str) -> Option<usize> {
+    re.captures(text).and_then(|captures| captures[1].parse().ok())
+}
+
+fn section_cohort(section: &str) -> Option<(i64, i64)> {
+    let (start, end) = cohort_range(section)?;
+    if section.contains("이후") {
+        Some((start, i64::MAX))
+    } else {
+        Some((start, end))
+    }
+}
+
+fn first_column<'a>(table: &CorpusTable, names: &[&'a str]) -> Option<&'a str> {
+    names.iter().copied().find(|name| table.column(name).is_some())
+}
+
+fn trailing_text(table: &CorpusTable, as_of: Option<&str>) -> String {
+    let Some(text) = get_file_text(table.file, as_of) else {
+        return String::new();
+    };
+    let after = table.rows.last().map(|row| row.line).unwrap_or(table.line);
+    text.lines()
+        .skip(after)
+        .take_while(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn condition_groups(table: &CorpusTable) -> Option<Vec<AreaGroup>> {
+    let group_column = first_column(table, GROUP_COLUMNS)?;
+    let area_column = first_column(table, AREA_COLUMNS)?;
+    let condition_column = first_column(table, CONDITION_COLUMNS)?;
+
+    let groups = table
+        .rows
+        .iter()
+        .map(|row| {
+            let condition = table.cell(row, condition_column).unwrap_or_default().to_string();
+            let min_courses = captured_count(&TOTAL_COURSES_RE, &condition)
+                .or_else(|| captured_count(&COURSES_RE, &condition))
+                .unwrap_or(1);
+            let min_areas = captured_count(&AREA_COUNT_RE, &condition).unwrap_or(
+                if condition.contains('각') { min_courses } else { 1 },
+            );
+            let compact_condition = compact(&condition);
+            AreaGroup {
+                name: table.cell(row, group_column).unwrap_or_default().to_string(),
+                areas: table
+                    .cell(row, area_column)
+                    .unwrap_or_default()
+                    .split(" / ")
+                    .map(str::trim)
+                    .filter(|area| !area.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                exclude_own_college: compact_condition.contains("개설과목제외"),
+                outside_own_field: compact_condition.contains("소속대학외"),
+                condition,
+                min_courses,
+                min_areas,
+            }
+        })
+        .collect();
+    Some(groups)
+}
+
+fn balance_group(table: &CorpusTable, as_of: Option<&str>) -> Option<AreaGroup> {
+    if !table.has_columns(&["영역", "분류"]) {
+        return None;
+    }
+    let condition = trailing_text(table, as_of)
+        .lines()
+        .map(|line| line.trim().trim_start_matches("- ").to_string())
+        .find(|line| AREA_COUNT_RE.is_match(line))?;
+    let min_areas = captured_count(&AREA_COUNT_RE, &condition)?;
+    Some(AreaGroup {
+        name: "BALANCE".to_string(),
+        areas: table
+            .rows
+            .iter()
+            .map(|row| {
+                format!(
+                    "{} {}",
+                    table.cell(row, "영역").unwrap_or_default(),
+                    table.cell(row, "분류").unwrap_or_default()
+                )
+            })
+            .collect(),
+        condition,
+        min_courses: min_areas,
+        min_areas,
+        exclude_own_college: false,
+        outside_own_field: false,
+    })
+}
+
+fn systems(as_of: Option<&str>) -> Vec<ElectiveSystem> {
+    table::extract_tables(PATH_LIBERAL_ELECTIVE, as_of)
+        .into_iter()
+        .filter_map(|table| {
+            let cohort = section_cohort(&table.section)?;
+            let groups = match balance_group(&table, as_of) {
+                Some(group) => vec![group],
+                None => condition_groups(&table)?,
+            };
+            Some(ElectiveSystem {
+                section: table.section.clone(),
+                cohort,
+                table,
+                groups,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn system_for(admission_year: i64, as_of: Option<&str>) -> Option<ElectiveSystem> {
+    systems(as_of)
+        .into_iter()
+        .find(|system| (system.cohort.0..=system.cohort.1).contains(&admission_year))
+}
+
+pub(crate) fn elective_choice(admission_year: i64, as_of: Option<&str>) -> Option<ElectiveChoice> {
+    systems(as_of).into_iter().find_map(|system| {
+        let note = trailing_text(&system.table, as_of)
+            .lines()
+            .map(|line| line.trim().trim_start_matches("- ").to_string())
+            .find(|line| CHOICE_RE.is_match(line))?;
+        let captures = CHOICE_RE.captures(&note)?;
+        let until = captures[1].parse::<i64>().ok()?;
+        let alternative_year = captures[2].parse::<i64>().ok()?;
+        (admission_year <= until).then(|| ElectiveChoice {
+            until,
+            alternative_year,
+            citation: system.table.citation(),
+            note: note.clone(),
+        })
+    })
+}
+
+fn cohort_summary(admission_year: i64, as_of: Option<&str>) -> Vec<Value> {
+    table::extract_tables(PATH_LIBERAL_ELECTIVE, as_of)
+        .into_iter()
+        .filter(|table| {
+            section_cohort(&table.section)
+                .map(|(start, end)| (start..=end).contains(&admission_year))
+                .unwrap_or(false)
+        })
+        .map(|table| {
+            json!({
+                "section": table.section,
+                "citation": table.citation(),
+                "rows": table.rows.iter().map(|row| table.row_map(row)).collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
+
+fn area_matches(area: &str, wanted: &str) -> bool {
+    let area = compact(area);
+    let wanted = compact(wanted);
+    !wanted.is_empty() && (area == wanted || area.starts_with(&wanted) || area.contains(&wanted))
+}
+
+fn locate_area<'a>(groups: &'a [AreaGroup], wanted: &str) -> Option<(&'a AreaGroup, &'a str)> {
+    let exact = groups.iter().find_map(|group| {
+        group
+            .areas
+            .iter()
+            .find(|area| compact(area) == compact(wanted))
+            .map(|area| (group, area.as_str()))
+    });
+    exact.or_else(|| {
+        groups.iter().find_map(|group| {
+            group
+                .areas
+                .iter()
+                .find(|area| area_matches(area, wanted))
+                .map(|area| (group, area.as_str()))
+        })
+    })
+}
+
+pub(crate) fn parse_elective_courses(
+    args: &Map<String, Value>,
+) -> std::result::Result<Vec<ElectiveCourse>, String> {
+    let items = args
+        .get("courses")
+        .and_then(Value::as_array)
+        .ok_or("'courses' is required and must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("courses[{index}] must be an object"))?;
+            let prefixed = |message: String| format!("courses[{index}]: {message}");
+            Ok(ElectiveCourse {
+                name: required_string(object, "name").map_err(prefixed)?,
+                area: required_string(object, "area").map_err(prefixed)?,
+                credits: optional_i64(object, "credits"),
+                offering_college: optional_string(object, "offering_college")
+                    .map(|college| college.trim().to_string())
+                    .filter(|college| !college.is_empty()),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn evaluate_system(
+    system: &ElectiveSystem,
+    courses: &[ElectiveCourse],
+    college: &str,
+) -> Value {
+    let mut group_results = Vec::new();
+    let mut unmatched = Vec::new();
+    let mut all_satisfied = true;
+    let mut needs_verification = Vec::new();
+
+    let mut placed: Vec<(&ElectiveCourse, &AreaGroup, &str)> = Vec::new();
+    for course in courses {
+        match locate_area(&system.groups, &course.area) {
+            Some((group, area)) => placed.push((course, group, area)),
+            None => unmatched.push(json!({ "name": course.name, "area": course.area })),
+        }
+    }
+
+    for group in &system.groups {
+        let mut counted_keys = BTreeSet::new();
+        let mut covered = BTreeSet::new();
+        let mut pending_keys = BTreeSet::new();
+        let mut pending_areas = BTreeSet::new();
+        let mut excluded = Vec::new();
+        let mut credits = 0;
+
+        for (course, _, area) in placed.iter().filter(|(_, g, _)| std::ptr::eq(*g, group)) {
+            if group.exclude_own_college {
+                match course.offering_college.as_deref() {
+                    Some(offering) if !college.is_empty() && offering == college => {
+                        excluded.push(json!({
+                            "name": course.name,
+                            "reason": "소속 단과대학 개설과목",
+                        }));
+                        continue;
+                    }
+                    Some(_) if !college.is_empty() => {}
+                    _ => {
+                        pending_keys.insert(course_key(&course.name));
+                        pending_areas.insert(area.to_string());
+                        continue;
+                    }
+                }
+            }
+            if counted_keys.insert(course_key(&course.name)) {
+                credits += course.credits.unwrap_or(0);
+            }
+            covered.insert(area.to_string());
+        }
+
+        let satisfied = counted_keys.len() >= group.min_courses && covered.len() >= group.min_areas;
+        let possible = counted_keys.union(&pending_keys).count() >= group.min_courses
+            && covered.union(&pending_areas).count() >= group.min_areas;
+        if !satisfied && possible {
+            needs_verification.push(format!(
+                "{}: 개설 단과대학(offering_college)과 소속 대학(college) 확인 필요",
+                group.name
+            ));
+        }
+        if satisfied && group.outside_own_field {
+            needs_verification.push(format!(
+                "{}: '{}' 조건의 소속대학 분야 구분은 코퍼스에 없음",
+                group.name, group.condition
+            ));
+        }
+        all_satisfied &= satisfied;
+
+        group_results.push(json!({
+            "group": group.name,
+            "condition": group.condition,
+            "required_courses": group.min_courses,
+            "required_areas": group.min_areas,
+            "counted_courses": counted_keys.len(),
+            "covered_areas": covered,
+            "credits": credits,
+            "remaining_areas": group
+                .areas
+                .iter()
+                .filter(|area| !covered.contains(*area))
+                .collect::<Vec<_>>(),
+            "excluded_courses": excluded,
+            "satisfied": satisfied,
+        }));
+    }
+
+    let judgement = if !needs_verification.is_empty() {
+        "판정 불가"
+    } else if all_satisfied {
+        "가능"
+    } else {
+        "불가"
+    };
+
+    json!({
+        "judgement": judgement,
+        "system": system.section,
+        "groups": group_results,
+        "unmatched_courses": unmatched,
+        "needs_verification": needs_verification,
+        "citation": system.table.citation(),
+    })
+}
+
+pub(crate) fn check_elective_areas(
+    courses: &[ElectiveCourse],
+    admission_year: i64,
+    college: &str,
+    as_of: Option<&str>,
+) -> Value {
+    let college = catalog::canonical_college(college, as_of);
+    let college = college.as_str();
+    let courses: Vec<ElectiveCourse> = courses
+        .iter()
+        .map(|course| ElectiveCourse {
+            name: course.name.clone(),
+            area: course.area.clone(),
+            credits: course.credits,
+            offering_college: course
+                .offering_college
+                .as_deref()
+                .map(|offering| catalog::canonical_college(offering, as_of)),
+        })
+        .collect();
+    let courses = courses.as_slice();
+    let own = system_for(admission_year, as_of);
+    let choice = elective_choice(admission_year, as_of);
+    let alternative = choice
+        .as_ref()
+        .and_then(|choice| system_for(choice.alternative_year, as_of))
+        .filter(|alt| own.as_ref().map(|own| own.section != alt.section).unwrap_or(true));
+
+    if own.is_none() && alternative.is_none() {
+        let summary = cohort_summary(admission_year, as_of);
+        return json!({
+            "judgement": "판정 불가",
+            "reason": if summary.is_empty() {
+                format!("교양 선택 문서에 {admission_year}학년도 입학자 이수체계가 없음")
+            } else {
+                format!("{admission_year}학년도 입학자 이수체계는 요약만 있고 영역별 최소 이수조건이 없음")
+            },
+            "admission_year": admission_year,
+            "cohort_tables": summary,
+            "citation": "(교양 선택.pdf, p.1~4)"
+        });
+    }
+
+    let mut options = Vec::new();
+    if let Some(system) = &own {
+        let mut option = evaluate_system(system, courses, college);
+        option["option"] = json!("입학년도 기준 이수체계");
+        options.push(option);
+    }
+    if let Some(system) = &alternative {
+        let mut option = evaluate_system(system, courses, college);
+        let (start, end) = system.cohort;
+        let label = if start == end { format!("{start}") } else { format!("{start}~{end}") };
+        option["option"] = json!(format!("{label}학년도 이수체계 선택"));
+        options.push(option);
+    }
+
+    let best = options
+        .iter()
+        .enumerate()
+        .max_by_key(|(idx, option)| {
+            (
+                judgement_rank(option["judgement"].as_str().unwrap_or_default()),
+                std::cmp::Reverse(*idx),
+            )
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or(0);
+    let mut result = options[best].clone();
+    result["admission_year"] = json!(admission_year);
+    result["college"] = json!(college);
+
+    let mut notes = vec!["과목의 영역(area)은 교양 선택 문서의 영역명 또는 'N영역'과 비교".to_string()];
+    let systems_used: Vec<&ElectiveSystem> = own.iter().chain(alternative.iter()).collect();
+    if systems_used
+        .iter()
+        .any(|system| system.groups.iter().any(|group| group.exclude_own_college))
+    {
+        notes.push("소속 단과대학 개설과목은 해당 역량군 이수로 인정하지 않음 (교양 선택.pdf, p.2)".to_string());
+    }
+    if result["unmatched_courses"].as_array().map(|v| !v.is_empty()).unwrap_or(false) {
+        notes.push("영역을 인식하지 못한 과목은 집계에서 제외".to_string());
+    }
+    if let Some(choice) = &choice {
+        if own.is_none() {
+            notes.push(format!(
+                "{admission_year}학년도 입학자 이수체계는 영역별 최소 이수조건이 없어 선택 가능한 이수체계로만 판단"
+            ));
+        }
+        notes.push(format!("{} {}", choice.note, choice.citation));
+        result["choice"] = json!({
+            "rule": choice.note,
+            "applies_until": choice.until,
+            "citation": choice.citation,
+            "chosen": result["option"].clone(),
+        });
+        result["options"] = json!(options);
+    }
+    result["notes"] = json!(notes);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(name: &str, area: &str, offering_college: Option<&str>) -> ElectiveCourse {
+        ElectiveCourse {
+            name: name.to_string(),
+            area: area.to_string(),
+            credits: Some(3),
+            offering_college: offering_college.map(str::to_string),
+        }
+    }
+
+    fn courses(creative_offering: Option<&str>) -> Vec<ElectiveCourse> {
+        vec![
+            course("리더십과 소통", "인성과 리더십", Some("인문대학")),
+            course("글쓰기와 토론", "한국어의사소통", Some("인문대학")),
+            course("현대문학의 이해", "문학·예술", Some("인문대학")),
+            course("컴퓨팅적 사고", "자연과학·공학·기술", creative_offering),
+        ]
+    }
+
+    #[test]
+    fn own_college_courses_are_excluded_in_the_2020_system() {
+        let system = system_for(2020, None).expect("2020 system");
+        assert_eq!(system.section, "2020학년도 입학자 교양선택 이수체계");
+
+        let result = evaluate_system(&system, &courses(Some("IT대학")), "IT대학");
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["groups"][2]["counted_courses"], 1);
+        assert_eq!(result["groups"][2]["excluded_courses"][0]["name"], "컴퓨팅적 사고");
+
+        assert_eq!(evaluate_system(&system, &courses(Some("경영대학")), "IT대학")["judgement"], "가능");
+
+        let unknown = evaluate_system(&system, &courses(None), "IT대학");
+        assert_eq!(unknown["judgement"], "판정 불가");
+        assert!(unknown["needs_verification"][0]
+            .as_str()
+            .unwrap_or_default()
+            .contains("offering_college"));
+    }
+
+    #[test]
+    fn entrants_up_to_2020_may_choose_the_2021_system() {
+        let choice = elective_choice(2020, None).expect("CHOICE_RE note");
+        assert_eq!((choice.until, choice.alternative_year), (2020, 2021));
+        assert!(elective_choice(2021, None).is_none());
+
+        let result = check_elective_areas(&courses(Some("IT대학")), 2020, "IT대", None);
+        assert_eq!(result["college"], "IT대학");
+        assert_eq!(result["judgement"], "가능");
+        assert_eq!(result["option"], "2021~2022학년도 이수체계 선택");
+        assert_eq!(result["choice"]["applies_until"], 2020);
+        let options = result["options"].as_array().expect("both systems");
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0]["judgement"], "불가");
+    }
+
+    #[test]
+    fn summary_only_cohorts_fall_back_to_the_chosen_system() {
+        let result = check_elective_areas(&courses(Some("IT대학")), 2017, "IT대학", None);
+        assert_eq!(result["options"].as_array().map(Vec::len), Some(1));
+        assert_eq!(result["option"], "2021~2022학년도 이수체계 선택");
+        assert!(result["notes"]
+            .as_array()
+            .expect("notes")
+            .iter()
+            .any(|note| note.as_str().unwrap_or_default().contains("선택 가능한 이수체계로만 판단")));
+    }
+}
@@ -0,0 +1,280 @@
+use serde_json::{json, Map, Value};
+
+use crate::load::parse_term;
+use crate::{catalog, extract_first_int, gaps, numeric_rule, optional_string, required_string, standing};
+
+pub(crate) const TERM_STATUSES: &[&str] = &["등록", "휴학", "계절학기"];
+pub(crate) const PROGRAMS: &[&str] = &["학사", "석사과정", "박사과정", "석박통합과정"];
+
+pub(crate) struct TermRecord {
+    pub label: String,
+    pub status: &'static str,
+}
+
+fn normalize_status(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "등록" | "재학" | "정규" => Some("등록"),
+        "휴학" => Some("휴학"),
+        "계절" | "계절학기" | "여름학기" | "겨울학기" => Some("계절학기"),
+        _ => None,
+    }
+}
+
+pub(crate) fn normalize_program(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "학사" | "학부" | "학사과정" => Some("학사"),
+        "석사" | "석사과정" => Some("석사과정"),
+        "박사" | "박사과정" => Some("박사과정"),
+        "석박통합" | "석박통합과정" | "석·박사통합과정" => Some("석박통합과정"),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_terms(args: &Map<String, Value>) -> std::result::Result<Vec<TermRecord>, String> {
+    let items = args
+        .get("terms")
+        .and_then(Value::as_array)
+        .ok_or("'terms' is required and must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("terms[{index}] must be an object"))?;
+            let label = required_string(object, "semester")
+                .map_err(|message| format!("terms[{index}]: {message}"))?
+                .trim()
+                .to_string();
+            let seasonal = matches!(parse_term(&label), Some((_, "여름학기" | "겨울학기")));
+            let status = match optional_string(object, "status") {
+                Some(raw) => normalize_status(&raw).ok_or_else(|| {
+                    format!("terms[{index}]: 'status' must be one of {}", TERM_STATUSES.join("/"))
+                })?,
+                None if seasonal => "계절학기",
+                None => "등록",
+            };
+            if seasonal && status != "계절학기" {
+                return Err(format!("terms[{index}]: 여름/겨울학기는 status가 계절학기여야 함"));
+            }
+            Ok(TermRecord { label, status })
+        })
+        .collect()
+}
+
+fn regular_rule(program: &str, five_year: bool, as_of: Option<&str>) -> Option<(i64, &'static str, String)> {
+    let label = match program {
+        "석사과정" | "박사과정" => "일반대학원 석사/박사 정규등록학기",
+        "석박통합과정" => "일반대학원 석·박사통합 정규등록학기",
+        _ if five_year => "건축학전공 정규등록학기",
+        _ => "학사 정규등록학기",
+    };
+    let (value, citation) = numeric_rule(label, as_of)?;
+    Some((extract_first_int(&value)?, label, citation))
+}
+
+pub(crate) fn track_enrollment(
+    terms: &[TermRecord],
+    program_level: &str,
+    college: &str,
+    department: &str,
+    as_of: Option<&str>,
+) -> Value {
+    let undergraduate = program_level == "학사";
+    let program = catalog::resolve(college, department, as_of);
+    let five_year = if undergraduate {
+        standing::five_year_program(&program, as_of)
+    } else {
+        Some(false)
+    };
+    let Some((regular, regular_label, regular_citation)) =
+        regular_rule(program_level, five_year == Some(true), as_of)
+    else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 정규등록학기 기준을 찾지 못함",
+            "citation": "(학칙.pdf, p.8, 제32조 5항)"
+        });
+    };
+
+    let mut registered = 0;
+    let mut leave = 0;
+    let mut seasonal = 0;
+    let timeline: Vec<Value> = terms
+        .iter()
+        .map(|term| {
+            let regular_index = match term.status {
+                "등록" => {
+                    registered += 1;
+                    Some(registered)
+                }
+                "휴학" => {
+                    leave += 1;
+                    None
+                }
+                _ => {
+                    seasonal += 1;
+                    None
+                }
+            };
+            json!({
+                "semester": term.label,
+                "status": term.status,
+                "registered_semester": regular_index.map(|index| format!("제{index}학기")),
+                "beyond_regular": regular_index.map(|index| index > regular),
+            })
+        })
+        .collect();
+
+    let exceeded = (registered - regular).max(0);
+    let past_regular = registered >= regular;
+    let registration = if exceeded > 0 {
+        format!(
+            "정규등록학기 {regular}학기를 {exceeded}학기 초과해 등록: 졸업요건을 못 채웠으면 추가 학기 등록이 필요하며 학점등록 등 세부사항은 시행세칙 소관 (학칙.pdf, p.8, 제32조 6항)"
+        )
+    } else if past_regular {
+        format!(
+            "정규등록학기 {regular}학기를 모두 등록: 졸업요건을 못 채우면 다음 학기는 정규학기 초과 등록이며 학점등록 등 세부사항은 시행세칙 소관 (학칙.pdf, p.8, 제32조 6항)"
+        )
+    } else {
+        format!(
+            "정규등록학기 {}학기 남음: 매학기 지정기간 내 등록금을 납부해 등록 (학칙.pdf, p.8, 제32조 2항)",
+            regular - registered
+        )
+    };
+
+    let length_label = match program_level {
+        "학사" if five_year == Some(true) => Some("건축학부 건축학전공 수업연한"),
+        "학사" => Some("학사 수업연한"),
+        _ => None,
+    };
+    let course_length = length_label
+        .and_then(|label| numeric_rule(label, as_of))
+        .map(|(value, citation)| json!({ "years": extract_first_int(&value), "rule": value, "citation": citation }));
+    let limit_rule = numeric_rule("재학연한", as_of);
+    let limit_deleted = limit_rule.as_ref().is_some_and(|(value, _)| value.contains("삭제"));
+    let enrollment_limit = match &limit_rule {
+        Some((value, citation)) => json!({
+            "limit": if limit_deleted { None } else { extract_first_int(value) },
+            "elapsed_semesters": registered + leave,
+            "remaining": Value::Null,
+            "rule": value,
+            "citation": citation,
+        }),
+        None => json!({
+            "limit": null,
+            "elapsed_semesters": registered + leave,
+            "remaining": null,
+            "rule": "law-numeric-rules.md에 재학연한 행 없음",
+            "citation": "(학칙.pdf, p.5, 제17조)",
+        }),
+    };
+
+    let mut notes = vec!["정규등록학기 수에는 휴학 학기와 계절학기를 포함하지 않음".to_string()];
+    if limit_deleted {
+        notes.push(
+            "제17조(재학연한)가 삭제되어 재학 기간 상한이 없으므로 enrollment_limit.remaining은 null: 휴학은 제27조 통산 한도로 별도 제한"
+                .to_string(),
+        );
+    }
+    if leave > 0 {
+        notes.push(format!("휴학 {leave}학기: 남은 통산 휴학 학기는 ssu_evaluate_leave로 확인"));
+    }
+    if undergraduate {
+        notes.push("조기졸업 시 수업연한 1년 단축 가능 (학칙.pdf, p.4, 제16조 3항 1호)".to_string());
+    }
+    if undergraduate && five_year.is_none() {
+        notes.push("건축학부 전공이 정해지지 않아 4년제(8학기) 기준으로 계산: 건축학전공이면 10학기".to_string());
+    }
+    let known_gap = past_regular
+        .then(|| gaps::gap_named("정규학기 초과 등록(학점등록)", as_of))
+        .flatten()
+        .map(|gap| gaps::gap_summary(&gap));
+
+    json!({
+        "program": program_level,
+        "department": (undergraduate && !college.is_empty()).then(|| program.summary()),
+        "five_year_program": five_year,
+        "terms": timeline,
+        "registered_semesters": registered,
+        "leave_semesters": leave,
+        "seasonal_terms": seasonal,
+        "regular_semesters": {
+            "rule": regular_label,
+            "required": regular,
+            "registered": registered,
+            "remaining": (regular - registered).max(0),
+            "exceeded": exceeded,
+            "citation": regular_citation,
+        },
+        "past_regular_semesters": past_regular,
+        "registration": registration,
+        "known_gap": known_gap,
+        "course_length": course_length,
+        "enrollment_limit": enrollment_limit,
+        "citation": regular_citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(value: Value) -> std::result::Result<Vec<TermRecord>, String> {
+        parse_terms(json!({ "terms": value }).as_object().expect("object"))
+    }
+
+    #[test]
+    fn only_registered_regular_terms_count_toward_regular_semesters() {
+        let records = terms(json!([
+            { "semester": "2022-1" },
+            { "semester": "2022-여름" },
+            { "semester": "2022-2", "status": "재학" },
+            { "semester": "2023-1", "status": "휴학" },
+            { "semester": "2023 동계" },
+            { "semester": "2023-2" },
+        ]))
+        .expect("valid terms");
+        assert_eq!(records[1].status, "계절학기");
+        assert_eq!(records[4].status, "계절학기");
+
+        let result = track_enrollment(&records, "학사", "IT대학", "컴퓨터학부", None);
+        assert_eq!(result["registered_semesters"], 3);
+        assert_eq!(result["leave_semesters"], 1);
+        assert_eq!(result["seasonal_terms"], 2);
+        assert_eq!(result["regular_semesters"]["required"], 8);
+        assert_eq!(result["regular_semesters"]["remaining"], 5);
+        assert_eq!(result["enrollment_limit"]["elapsed_semesters"], 4);
+        assert_eq!(result["terms"][5]["registered_semester"], "제3학기");
+        assert!(result["terms"][1]["registered_semester"].is_null());
+        assert_eq!(result["past_regular_semesters"], false);
+    }
+
+    #[test]
+    fn architecture_counts_ten_regular_semesters() {
+        let records: Vec<TermRecord> = (2016..2021)
+            .flat_map(|year| [format!("{year}-1"), format!("{year}-2")])
+            .chain(["2021-1".to_string()])
+            .map(|label| TermRecord { label, status: "등록" })
+            .collect();
+        let result = track_enrollment(&records, "학사", "공과대학", "건축학전공", None);
+        assert_eq!(result["five_year_program"], true);
+        assert_eq!(result["regular_semesters"]["required"], 10);
+        assert_eq!(result["regular_semesters"]["exceeded"], 1);
+        assert_eq!(result["terms"][10]["beyond_regular"], true);
+        assert_eq!(result["past_regular_semesters"], true);
+    }
+
+    #[test]
+    fn term_input_errors_name_the_offending_field() {
+        assert_eq!(
+            terms(json!([{ "semester": "2024-1" }, { "semester": "2024-여름", "status": "등록" }])).err().as_deref(),
+            Some("terms[1]: 여름/겨울학기는 status가 계절학기여야 함")
+        );
+        assert_eq!(
+            terms(json!([{ "semester": "2024-1", "status": "수료" }])).err(),
+            Some(format!("terms[0]: 'status' must be one of {}", TERM_STATUSES.join("/")))
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+
+use crate::hangul::compose_hangul;
+
+pub(crate) fn parse_front_matter(text: &str) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+    for line in text.lines().skip_while(|line| !line.starts_with("# ")).skip(1) {
+        if line.starts_with("## ") {
+            break;
+        }
+        let Some(item) = line.strip_prefix("- ") else {
+            continue;
+        };
+        let Some((key, value)) = item.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('`').trim();
+        if !value.is_empty() {
+            fields.insert(key.trim().to_string(), compose_hangul(value));
+        }
+    }
+    fields
+}
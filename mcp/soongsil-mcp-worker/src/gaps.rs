@@ -0,0 +1,113 @@
+use serde_json::{json, Value};
+
+use crate::{get_file_text, split_pipe_row, PATH_SOURCE_MAP};
+
+pub(crate) const GAP_JUDGEMENT: &str = "판정 불가(근거 문서 없음)";
+
+pub(crate) struct KnownGap {
+    pub name: String,
+    pub description: String,
+    pub topic_terms: Vec<String>,
+    pub detail_terms: Vec<String>,
+    pub required_source: String,
+    pub related_citation: String,
+}
+
+fn split_terms(cell: &str) -> Vec<String> {
+    cell.split(',')
+        .map(|term| term.trim().to_lowercase())
+        .filter(|term| !term.is_empty())
+        .collect()
+}
+
+pub(crate) fn registry(as_of: Option<&str>) -> Vec<KnownGap> {
+    let text = get_file_text(PATH_SOURCE_MAP, as_of).unwrap_or_default();
+    let mut gaps = Vec::new();
+    let mut in_gaps = false;
+
+    for line in text.lines() {
+        let stripped = line.trim();
+        if stripped.starts_with("## ") {
+            in_gaps = stripped == "## Known Gaps";
+            continue;
+        }
+        if !in_gaps || !stripped.starts_with('|') {
+            continue;
+        }
+        let cells = split_pipe_row(stripped);
+        if cells.len() < 6 || cells[0] == "Gap" || cells[0].starts_with("---") {
+            continue;
+        }
+        gaps.push(KnownGap {
+            name: cells[0].clone(),
+            description: cells[1].clone(),
+            topic_terms: split_terms(&cells[2]),
+            detail_terms: split_terms(&cells[3]),
+            required_source: cells[4].clone(),
+            related_citation: cells[5].clone(),
+        });
+    }
+
+    gaps
+}
+
+pub(crate) fn find_gap(question: &str, as_of: Option<&str>) -> Option<(KnownGap, Vec<String>)> {
+    let lowered = question.to_lowercase();
+    registry(as_of).into_iter().find_map(|gap| {
+        let topic = gap.topic_terms.iter().find(|term| lowered.contains(term.as_str()))?;
+        let detail = gap.detail_terms.iter().find(|term| lowered.contains(term.as_str()))?;
+        let matched = vec![topic.clone(), detail.clone()];
+        Some((gap, matched))
+    })
+}
+
+pub(crate) fn gap_named(name: &str, as_of: Option<&str>) -> Option<KnownGap> {
+    registry(as_of).into_iter().find(|gap| gap.name == name)
+}
+
+pub(crate) fn gap_result(gap: &KnownGap, matched_keywords: &[String]) -> Value {
+    json!({
+        "judgement": GAP_JUDGEMENT,
+        "known_gap": gap.name,
+        "reason": gap.description,
+        "matched_keywords": matched_keywords,
+        "required_action": format!("{} 제공 필요", gap.required_source),
+        "citation": format!("{} + source-map known gap", gap.related_citation),
+    })
+}
+
+pub(crate) fn gap_summary(gap: &KnownGap) -> Value {
+    json!({
+        "gap": gap.name,
+        "description": gap.description,
+        "required_source": gap.required_source,
+        "related_citation": gap.related_citation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_gap_named_by_a_tool_is_registered() {
+        for name in [
+            "장학금 선발 기준",
+            "재수강 횟수/학점 한도",
+            "공학교육인증 이수학점",
+            "정규학기 초과 등록(학점등록)",
+            "수업료 반환 기준(자퇴·기간 외)",
+            "교양필수 재수강 대체과목",
+        ] {
+            assert!(gap_named(name, None).is_some(), "missing Known Gap: {name}");
+        }
+    }
+
+    #[test]
+    fn question_needs_topic_and_detail_keywords() {
+        let (gap, matched) = find_gap("재수강은 최대 몇 번까지 가능한가요?", None).expect("gap");
+        assert_eq!(gap.name, "재수강 횟수/학점 한도");
+        assert_eq!(matched, vec!["재수강".to_string(), "몇 번".to_string()]);
+        assert!(find_gap("재수강 신청 기간", None).is_none());
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::load::parse_term;
+use crate::transcript::{course_key, normalize_grade, GRADES};
+use crate::{article_citation, optional_string, required_i64, required_string, table, PATH_LAW_NUMERIC};
+
+pub(crate) const RETAKE_POLICIES: &[&str] = &["대체", "모두 반영"];
+pub(crate) const GPA_BASES: &[&str] = &["직전학기", "누계"];
+pub(crate) const UNDATED: &str = "(학기 미입력)";
+
+pub(crate) struct GradedCourse {
+    pub index: usize,
+    pub name: String,
+    pub credits: i64,
+    pub grade: Option<String>,
+    pub semester: Option<String>,
+}
+
+impl GradedCourse {
+    fn semester_label(&self) -> &str {
+        self.semester.as_deref().unwrap_or(UNDATED)
+    }
+}
+
+pub(crate) struct SemesterGpa {
+    pub label: String,
+    pub gpa: Option<f64>,
+    pub earned_credits: i64,
+}
+
+pub(crate) struct GpaReport {
+    pub cumulative: Option<f64>,
+    pub cumulative_earned: i64,
+    pub semesters: Vec<SemesterGpa>,
+    pub value: Value,
+}
+
+impl GpaReport {
+    pub(crate) fn latest(&self) -> Option<&SemesterGpa> {
+        self.semesters.iter().rev().find(|semester| semester.label != UNDATED)
+    }
+
+    pub(crate) fn on_basis(&self, basis: &str) -> (Option<f64>, Option<i64>, String) {
+        if basis == "누계" {
+            return (self.cumulative, Some(self.cumulative_earned), "누계".to_string());
+        }
+        match self.latest() {
+            Some(semester) => (semester.gpa, Some(semester.earned_credits), format!("직전학기({})", semester.label)),
+            None => (None, None, "직전학기(semester 미입력)".to_string()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    points_hundredths: i64,
+    gpa_credits: i64,
+    earned_credits: i64,
+    pass_fail_credits: i64,
+    courses: usize,
+}
+
+impl Tally {
+    fn add(&mut self, course: &GradedCourse, points: Option<f64>) {
+        self.courses += 1;
+        match points {
+            Some(points) => {
+                self.points_hundredths += (points * 100.0).round() as i64 * course.credits;
+                self.gpa_credits += course.credits;
+                if course.grade.as_deref() != Some("F") {
+                    self.earned_credits += course.credits;
+                }
+            }
+            None => {
+                self.pass_fail_credits += course.credits;
+                if course.grade.as_deref() == Some("P") {
+                    self.earned_credits += course.credits;
+                }
+            }
+        }
+    }
+
+    fn gpa(&self) -> Option<f64> {
+        (self.gpa_credits > 0).then(|| self.points_hundredths as f64 / (self.gpa_credits * 100) as f64)
+    }
+
+    fn summary(&self) -> Value {
+        json!({
+            "gpa": self.gpa().map(round2),
+            "gpa_raw": self.gpa(),
+            "grade_points": self.points_hundredths as f64 / 100.0,
+            "gpa_credits": self.gpa_credits,
+            "earned_credits": self.earned_credits,
+            "pass_fail_credits": self.pass_fail_credits,
+            "course_count": self.courses,
+        })
+    }
+}
+
+pub(crate) fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+pub(crate) fn format_gpa(value: f64) -> String {
+    let rounded = round2(value);
+    if (rounded - value).abs() < 1e-9 {
+        format!("{rounded:.2}")
+    } else {
+        format!("{rounded:.2}(원값 {value:.4})")
+    }
+}
+
+pub(crate) fn parse_graded_courses(args: &Map<String, Value>) -> std::result::Result<Vec<GradedCourse>, String> {
+    let items = args
+        .get("courses")
+        .and_then(Value::as_array)
+        .ok_or("'courses' is required and must be an array")?;
+    if items.is_empty() {
+        return Err("'courses' must contain at least one course".to_string());
+    }
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("courses[{index}] must be an object"))?;
+            let prefixed = |message: String| format!("courses[{index}]: {message}");
+            let semester = optional_string(object, "semester")
+                .map(|semester| semester.trim().to_string())
+                .filter(|semester| !semester.is_empty());
+            if let Some(label) = semester.as_deref().filter(|label| parse_term(label).is_none()) {
+                return Err(prefixed(format!(
+                    "'semester' must be a term such as 2024-1, 2024-2 or 2024-여름 (got '{label}')"
+                )));
+            }
+            let grade = match optional_string(object, "grade").filter(|grade| !grade.trim().is_empty()) {
+                Some(raw) => Some(normalize_grade(&raw).ok_or_else(|| {
+                    prefixed(format!("'grade' must be one of {} (got '{raw}')", GRADES.join("/")))
+                })?),
+                None => None,
+            };
+            let credits = required_i64(object, "credits").map_err(prefixed)?;
+            if credits <= 0 {
+                return Err(prefixed("'credits' must be a positive integer".to_string()));
+            }
+            Ok(GradedCourse {
+                index,
+                name: required_string(object, "name").map_err(prefixed)?.trim().to_string(),
+                credits,
+                grade,
+                semester,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn retake_policy(args: &Map<String, Value>) -> std::result::Result<&'static str, String> {
+    match optional_string(args, "retake_policy") {
+        None => Ok(RETAKE_POLICIES[0]),
+        Some(raw) => RETAKE_POLICIES
+            .iter()
+            .find(|policy| **policy == raw.trim())
+            .copied()
+            .ok_or_else(|| format!("'retake_policy' must be one of {}", RETAKE_POLICIES.join("/"))),
+    }
+}
+
+pub(crate) fn gpa_basis(args: &Map<String, Value>) -> std::result::Result<&'static str, String> {
+    match optional_string(args, "gpa_basis") {
+        None => Ok(GPA_BASES[0]),
+        Some(raw) => GPA_BASES
+            .iter()
+            .find(|basis| **basis == raw.trim())
+            .copied()
+            .ok_or_else(|| format!("'gpa_basis' must be one of {}", GPA_BASES.join("/"))),
+    }
+}
+
+pub(crate) fn report_from_args(
+    args: &Map<String, Value>,
+    as_of: Option<&str>,
+) -> std::result::Result<Option<GpaReport>, String> {
+    if !args.contains_key("courses") {
+        return Ok(None);
+    }
+    let courses = parse_graded_courses(args)?;
+    Ok(Some(calculate(&courses, retake_policy(args)?, as_of)))
+}
+
+fn grade_scale(as_of: Option<&str>) -> Option<(BTreeMap<String, Option<f64>>, String)> {
+    let table = table::find_table(PATH_LAW_NUMERIC, as_of, &["등급", "평점"])?;
+    let mut citation = None;
+    let scale = table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let grade = normalize_grade(table.cell(row, "등급")?)?;
+            let points = table.cell(row, "평점")?.trim().parse::<f64>().ok();
+            if points.is_some() && citation.is_none() {
+                citation = table.cell(row, "근거").map(article_citation);
+            }
+            Some((grade, points))
+        })
+        .collect();
+    Some((scale, citation.unwrap_or_else(|| "(학칙.pdf, p.57, 별표 4)".to_string())))
+}
+
+pub(crate) fn validate_gpa(gpa: f64, as_of: Option<&str>) -> std::result::Result<f64, String> {
+    let max = grade_scale(as_of)
+        .and_then(|(scale, _)| scale.values().flatten().copied().reduce(f64::max))
+        .unwrap_or(4.5);
+    if !(0.0..=max).contains(&gpa) {
+        return Err(format!("'gpa' must be between 0 and {max} (학칙.pdf, p.57, 별표 4)"));
+    }
+    Ok(gpa)
+}
+
+pub(crate) fn semester_order(label: &str) -> (i64, u8, String) {
+    match parse_term(label) {
+        Some((year, term)) => {
+            let order = match term {
+                "1학기" => 1,
+                "여름학기" => 2,
+                "2학기" => 3,
+                _ => 4,
+            };
+            (year, order, String::new())
+        }
+        None if label == UNDATED => (i64::MAX, u8::MAX, String::new()),
+        None => (i64::MAX - 1, 0, label.to_string()),
+    }
+}
+
+pub(crate) fn calculate(courses: &[GradedCourse], policy: &str, as_of: Option<&str>) -> GpaReport {
+    let Some((scale, scale_citation)) = grade_scale(as_of) else {
+        return GpaReport {
+            cumulative: None,
+            cumulative_earned: 0,
+            semesters: vec![],
+            value: json!({
+                "judgement": "판정 불가",
+                "reason": "law-numeric-rules.md에서 별표 4 성적 평가표를 찾지 못함",
+                "citation": "(학칙.pdf, p.12, 제53조 2항)"
+            }),
+        };
+    };
+    let points_of = |course: &GradedCourse| course.grade.as_ref().and_then(|grade| scale.get(grade).copied().flatten());
+
+    let graded: Vec<&GradedCourse> = courses.iter().filter(|course| course.grade.is_some()).collect();
+    let mut attempts: BTreeMap<String, Vec<&GradedCourse>> = BTreeMap::new();
+    for course in graded.iter().filter(|course| points_of(course).is_some()) {
+        attempts.entry(course_key(&course.name)).or_default().push(course);
+    }
+    let mut replaced: Vec<usize> = Vec::new();
+    let mut retakes = Vec::new();
+    for group in attempts.values_mut() {
+        group.sort_by_key(|course| (semester_order(course.semester_label()), course.index));
+        let Some((last, earlier)) = group.split_last().filter(|(_, earlier)| !earlier.is_empty()) else {
+            continue;
+        };
+        replaced.extend(earlier.iter().map(|course| course.index));
+        retakes.push(json!({
+            "name": last.name,
+            "attempts": group
+                .iter()
+                .map(|course| json!({ "semester": course.semester, "grade": course.grade, "credits": course.credits }))
+                .collect::<Vec<_>>(),
+            "counted_semester": last.semester,
+            "counted_grade": last.grade,
+        }));
+    }
+    let replace = policy == "대체";
+
+    let mut cumulative = Tally::default();
+    let mut all_attempts = Tally::default();
+    let mut by_semester: BTreeMap<(i64, u8, String), (String, Tally)> = BTreeMap::new();
+    for course in &graded {
+        let points = points_of(course);
+        all_attempts.add(course, points);
+        if !(replace && replaced.contains(&course.index)) {
+            cumulative.add(course, points);
+        }
+        let label = course.semester_label().to_string();
+        by_semester
+            .entry(semester_order(&label))
+            .or_insert_with(|| (label, Tally::default()))
+            .1
+            .add(course, points);
+    }
+
+    let semesters: Vec<SemesterGpa> = by_semester
+        .values()
+        .map(|(label, tally)| SemesterGpa {
+            label: label.clone(),
+            gpa: tally.gpa(),
+            earned_credits: tally.earned_credits,
+        })
+        .collect();
+    let semester_values: Vec<Value> = by_semester
+        .values()
+        .map(|(label, tally)| {
+            let mut summary = tally.summary();
+            summary["semester"] = json!(label);
+            summary
+        })
+        .collect();
+    let pass_fail: Vec<Value> = graded
+        .iter()
+        .filter(|course| points_of(course).is_none())
+        .map(|course| json!({ "name": course.name, "semester": course.semester, "grade": course.grade }))
+        .collect();
+    let in_progress: Vec<&str> = courses
+        .iter()
+        .filter(|course| course.grade.is_none())
+        .map(|course| course.name.as_str())
+        .collect();
+
+    let mut notes = vec![
+        format!("등급별 평점은 별표 4 {scale_citation}, F는 0.0으로 평점 계산에 포함"),
+        "P/NP(P/F 평가 과목)는 평점누계와 평점평균 계산에서 제외 (학칙.pdf, p.12, 제53조 3항)".to_string(),
+        "학사과정 취득학점은 D- 이상과 P (학칙.pdf, p.12, 제53조 4항)".to_string(),
+        "평점평균의 소수점 처리 기준은 코퍼스에 없어 gpa는 소수 셋째 자리에서 반올림해 표시하고, 기준 비교는 원값(gpa_raw)으로 함".to_string(),
+    ];
+    if !retakes.is_empty() {
+        notes.push(match replace {
+            true => "재수강: 같은 과목명의 마지막 응시 성적만 누계에 반영(대체). 학칙에 대체 방식 근거가 없어 cumulative_all_attempts도 함께 표시".to_string(),
+            false => "재수강: 모든 응시 성적을 누계에 반영".to_string(),
+        });
+        notes.push("재수강 성적 상한·횟수 한도는 코퍼스에 근거 문서가 없음(source-map Known Gaps)".to_string());
+        notes.push("학기별 평점은 재수강 대체와 관계없이 그 학기에 받은 성적으로 계산".to_string());
+    }
+    if !in_progress.is_empty() {
+        notes.push("성적이 없는 과목은 수강중으로 보고 계산에서 제외".to_string());
+    }
+    if courses.iter().any(|course| course.semester.is_none()) {
+        notes.push(format!("semester가 없는 과목은 '{UNDATED}'로 묶어 학기별 평점에서 따로 표시"));
+    }
+
+    let mut report = GpaReport {
+        cumulative: cumulative.gpa(),
+        cumulative_earned: cumulative.earned_credits,
+        semesters,
+        value: Value::Null,
+    };
+    let latest = report.latest().map(|semester| semester.label.clone());
+    report.value = json!({
+        "retake_policy": policy,
+        "cumulative": cumulative.summary(),
+        "cumulative_all_attempts": (replace && !retakes.is_empty()).then(|| all_attempts.summary()),
+        "semesters": semester_values,
+        "latest_semester": latest,
+        "retakes": retakes,
+        "pass_fail": pass_fail,
+        "in_progress": in_progress,
+        "scale_citation": scale_citation,
+        "citation": "(학칙.pdf, p.12, 제53조 2항)",
+        "notes": notes,
+    });
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(index: usize, name: &str, credits: i64, grade: &str, semester: &str) -> GradedCourse {
+        GradedCourse {
+            index,
+            name: name.to_string(),
+            credits,
+            grade: Some(grade.to_string()),
+            semester: Some(semester.to_string()),
+        }
+    }
+
+    #[test]
+    fn grade_points_follow_table_4() {
+        let (scale, citation) = grade_scale(None).expect("별표 4");
+        assert_eq!(citation, "(학칙.pdf, p.57, 별표 4)");
+        for (grade, points) in [
+            ("A+", 4.5),
+            ("A0", 4.3),
+            ("A-", 4.0),
+            ("B+", 3.5),
+            ("C0", 2.3),
+            ("D-", 1.0),
+            ("F", 0.0),
+        ] {
+            assert_eq!(scale[grade], Some(points), "{grade}");
+        }
+
+        let report = calculate(
+            &[course(0, "자료구조", 3, "A+", "2024-1"), course(1, "선형대수", 2, "B0", "2024-1")],
+            "대체",
+            None,
+        );
+        let cumulative = report.cumulative.expect("gpa");
+        assert!((cumulative - 4.02).abs() < 1e-9);
+        assert_eq!(report.cumulative_earned, 5);
+    }
+
+    #[test]
+    fn retake_replaces_earlier_attempts_in_term_order() {
+        let courses = [
+            course(0, "자료구조", 3, "C0", "2024-2"),
+            course(1, "자료구조", 3, "F", "2024-여름"),
+            course(2, "운영체제", 3, "A0", "2024-1"),
+        ];
+
+        let replaced = calculate(&courses, "대체", None);
+        assert!((replaced.cumulative.expect("gpa") - (2.3 * 3.0 + 4.3 * 3.0) / 6.0).abs() < 1e-9);
+        assert_eq!(replaced.value["retakes"][0]["counted_semester"], "2024-2");
+        assert_eq!(replaced.value["cumulative_all_attempts"]["gpa_credits"], 9);
+
+        let all = calculate(&courses, "모두 반영", None);
+        assert!((all.cumulative.expect("gpa") - (2.3 + 0.0 + 4.3) * 3.0 / 9.0).abs() < 1e-9);
+        assert!(all.value["cumulative_all_attempts"].is_null());
+    }
+
+    fn parse(courses: Value) -> std::result::Result<Vec<GradedCourse>, String> {
+        let args = json!({ "courses": courses });
+        parse_graded_courses(args.as_object().expect("object"))
+    }
+
+    #[test]
+    fn course_input_errors_name_the_offending_field() {
+        assert_eq!(parse(json!([])).err().as_deref(), Some("'courses' must contain at least one course"));
+        assert_eq!(
+            parse(json!([
+                { "name": "자료구조", "credits": 3, "grade": "A0", "semester": "2024-1" },
+                { "name": "운영체제", "credits": 3, "grade": "A0", "semester": "2024 봄" },
+            ]))
+            .err()
+            .as_deref(),
+            Some("courses[1]: 'semester' must be a term such as 2024-1, 2024-2 or 2024-여름 (got '2024 봄')")
+        );
+        assert!(parse(json!([{ "name": "자료구조", "credits": 3, "grade": "E" }]))
+            .err()
+            .unwrap_or_default()
+            .starts_with("courses[0]: 'grade' must be one of"));
+        assert_eq!(
+            parse(json!([{ "name": "자료구조", "credits": 0, "grade": "A0" }])).err().as_deref(),
+            Some("courses[0]: 'credits' must be a positive integer")
+        );
+
+        let parsed = parse(json!([{ "name": "자료구조", "credits": 3, "grade": "a", "semester": " " }])).expect("valid");
+        assert_eq!(parsed[0].grade.as_deref(), Some("A0"));
+        assert!(parsed[0].semester.is_none());
+    }
+}
@@ -0,0 +1,27 @@
+pub(crate) fn compose_hangul(text: &str) -> String {
+    let mut out: Vec<char> = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        let code = c as u32;
+        let composed = match out.last().map(|prev| *prev as u32) {
+            Some(prev) if (0x1100..=0x1112).contains(&prev) && (0x1161..=0x1175).contains(&code) => {
+                char::from_u32(0xAC00 + ((prev - 0x1100) * 21 + (code - 0x1161)) * 28)
+            }
+            Some(prev)
+                if (0xAC00..=0xD7A3).contains(&prev)
+                    && (prev - 0xAC00) % 28 == 0
+                    && (0x11A8..=0x11C2).contains(&code) =>
+            {
+                char::from_u32(prev + (code - 0x11A7))
+            }
+            _ => None,
+        };
+        match composed {
+            Some(syllable) => {
+                out.pop();
+                out.push(syllable);
+            }
+            None => out.push(c),
+        }
+    }
+    out.into_iter().collect()
+}
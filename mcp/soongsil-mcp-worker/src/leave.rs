@@ -0,0 +1,440 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::{extract_first_int, number_to_i64, numeric_rule, optional_string};
+
+static BEFORE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{4})학년도 이전 신입학자\s*(\d+)학기").expect("BEFORE_RE compile failure")
+});
+
+pub(crate) const LEAVE_TYPES: &[&str] = &["일반", "질병", "군입대", "임신·출산·육아", "창업", "장기근무"];
+pub(crate) const ADMISSION_CATEGORIES: &[&str] = &["신입학", "편입학", "석사과정", "박사과정", "석박통합과정"];
+
+pub(crate) struct LeaveRecord {
+    pub kind: &'static str,
+    pub semesters: i64,
+}
+
+pub(crate) struct LeaveRequest<'a> {
+    pub category: &'a str,
+    pub admission_year: Option<i64>,
+    pub registered_semesters: Option<i64>,
+    pub consecutive_semesters: i64,
+    pub history: Vec<LeaveRecord>,
+    pub request: LeaveRecord,
+    pub request_given: bool,
+}
+
+pub(crate) fn normalize_leave_type(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "일반" | "일반휴학" | "개인" => Some("일반"),
+        "질병" | "질병휴학" | "입원" => Some("질병"),
+        "군입대" | "군" | "군휴학" | "병역" => Some("군입대"),
+        "임신·출산·육아" | "임신" | "출산" | "육아" | "육아휴학" => Some("임신·출산·육아"),
+        "창업" | "창업휴학" => Some("창업"),
+        "장기근무" | "해외근무" | "지방근무" => Some("장기근무"),
+        _ => None,
+    }
+}
+
+pub(crate) fn normalize_category(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "신입학" | "신입학자" | "신입" => Some("신입학"),
+        "편입학" | "편입학자" | "편입" => Some("편입학"),
+        "석사" | "석사과정" => Some("석사과정"),
+        "박사" | "박사과정" => Some("박사과정"),
+        "석박통합" | "석박통합과정" | "석·박사통합과정" => Some("석박통합과정"),
+        _ => None,
+    }
+}
+
+fn parse_record(object: &Map<String, Value>, label: &str) -> std::result::Result<LeaveRecord, String> {
+    let kind = match optional_string(object, "type") {
+        Some(raw) => normalize_leave_type(&raw)
+            .ok_or_else(|| format!("{label}: 'type' must be one of {}", LEAVE_TYPES.join("/")))?,
+        None => "일반",
+    };
+    let semesters = match object.get("semesters") {
+        None => 1,
+        Some(value) => number_to_i64(value)
+            .filter(|semesters| *semesters > 0)
+            .ok_or_else(|| format!("{label}: 'semesters' must be a positive integer"))?,
+    };
+    Ok(LeaveRecord { kind, semesters })
+}
+
+pub(crate) fn parse_history(args: &Map<String, Value>) -> std::result::Result<Vec<LeaveRecord>, String> {
+    let Some(items) = args.get("leaves") else {
+        return Ok(vec![]);
+    };
+    let items = items.as_array().ok_or("'leaves' must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("leaves[{index}] must be an object"))?;
+            parse_record(object, &format!("leaves[{index}]"))
+        })
+        .collect()
+}
+
+pub(crate) fn parse_request(args: &Map<String, Value>) -> std::result::Result<Option<LeaveRecord>, String> {
+    match args.get("request") {
+        None => Ok(None),
+        Some(value) => {
+            let object = value.as_object().ok_or("'request' must be an object")?;
+            parse_record(object, "request").map(Some)
+        }
+    }
+}
+
+fn is_undergraduate(category: &str) -> bool {
+    matches!(category, "신입학" | "편입학")
+}
+
+fn rule_int(label: &str, as_of: Option<&str>) -> Option<(i64, String)> {
+    numeric_rule(label, as_of).and_then(|(value, citation)| Some((extract_first_int(&value)?, citation)))
+}
+
+fn total_limit(
+    category: &str,
+    admission_year: Option<i64>,
+    as_of: Option<&str>,
+    notes: &mut Vec<String>,
+) -> Option<(i64, String)> {
+    let label = match category {
+        "신입학" => "통산 휴학(학사 신입학자)",
+        "편입학" => "통산 휴학(학사 편입학자)",
+        "석사과정" => "통산 휴학(석사과정)",
+        _ => "통산 휴학(박사/석박통합)",
+    };
+    let (value, citation) = numeric_rule(label, as_of)?;
+    let base = extract_first_int(&value)?;
+    let Some(caps) = BEFORE_RE.captures(&value) else {
+        return Some((base, citation));
+    };
+    let cutoff: i64 = caps[1].parse().ok()?;
+    let legacy: i64 = caps[2].parse().ok()?;
+    match admission_year {
+        Some(year) if year <= cutoff => Some((legacy, citation)),
+        Some(_) => Some((base, citation)),
+        None => {
+            notes.push(format!(
+                "admission_year 미입력: {}학년도 이후 신입학자 기준 {base}학기로 계산({cutoff}학년도 이전 신입학자는 {legacy}학기)",
+                cutoff + 1
+            ));
+            Some((base, citation))
+        }
+    }
+}
+
+fn excluded_cap(kind: &str, undergraduate: bool, as_of: Option<&str>) -> Option<(Option<i64>, String)> {
+    let label = match kind {
+        "군입대" => "통산 산입 제외(병역)",
+        "창업" => "통산 산입 제외(창업휴학)",
+        "임신·출산·육아" => "통산 산입 제외(임신·출산·육아)",
+        "장기근무" if !undergraduate => "통산 산입 제외(장기근무 등)",
+        _ => return None,
+    };
+    let (value, citation) = numeric_rule(label, as_of)?;
+    Some((extract_first_int(&value), citation))
+}
+
+fn consecutive_limit(kind: &str, undergraduate: bool, as_of: Option<&str>) -> Option<(Option<i64>, String, bool)> {
+    let label = match kind {
+        "군입대" => "연속 휴학 예외(병역)",
+        "임신·출산·육아" => "연속 휴학 예외(임신·출산·육아)",
+        "장기근무" if !undergraduate => "연속 휴학 예외(장기근무 등)",
+        _ => "연속 휴학",
+    };
+    let (value, citation) = numeric_rule(label, as_of)?;
+    Some((extract_first_int(&value), citation, label != "연속 휴학"))
+}
+
+fn counted(kind: &str, semesters: i64, undergraduate: bool, as_of: Option<&str>) -> i64 {
+    match excluded_cap(kind, undergraduate, as_of) {
+        Some((None, _)) => 0,
+        Some((Some(cap), _)) => (semesters - cap).max(0),
+        None => semesters,
+    }
+}
+
+fn check(name: &str, passed: Option<bool>, detail: String, citation: &str) -> Value {
+    json!({
+        "check": name,
+        "passed": passed,
+        "detail": detail,
+        "citation": citation,
+    })
+}
+
+pub(crate) fn evaluate_leave(request: &LeaveRequest, as_of: Option<&str>) -> Value {
+    let undergraduate = is_undergraduate(request.category);
+    let mut notes = Vec::new();
+    let (Some((limit, limit_citation)), Some((first_window, first_citation)), Some((startup_cap, startup_citation))) = (
+        total_limit(request.category, request.admission_year, as_of, &mut notes),
+        rule_int("입학 직후 휴학 제한", as_of),
+        rule_int("창업휴학", as_of),
+    ) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 제27조 휴학 기준 행을 찾지 못함",
+            "citation": "(학칙.pdf, p.6, 제27조)"
+        });
+    };
+
+    let mut usage: Vec<(&str, i64)> = Vec::new();
+    for record in &request.history {
+        match usage.iter_mut().find(|(kind, _)| *kind == record.kind) {
+            Some((_, total)) => *total += record.semesters,
+            None => usage.push((record.kind, record.semesters)),
+        }
+    }
+    let used = |kind: &str| {
+        usage
+            .iter()
+            .find(|(used_kind, _)| *used_kind == kind)
+            .map(|(_, total)| *total)
+            .unwrap_or(0)
+    };
+    let counted_total: i64 = usage
+        .iter()
+        .map(|(kind, total)| counted(kind, *total, undergraduate, as_of))
+        .sum();
+    let excluded: Vec<Value> = usage
+        .iter()
+        .filter_map(|(kind, total)| {
+            let (_, citation) = excluded_cap(kind, undergraduate, as_of)?;
+            Some(json!({
+                "type": kind,
+                "semesters": total,
+                "excluded": total - counted(kind, *total, undergraduate, as_of),
+                "citation": citation,
+            }))
+        })
+        .collect();
+    if undergraduate && (usage.iter().any(|(kind, _)| *kind == "장기근무") || request.request.kind == "장기근무") {
+        notes.push("장기근무 휴학은 대학원 과정 사유라 학사과정에서는 일반휴학으로 통산에 산입".to_string());
+    }
+
+    let kind = request.request.kind;
+    let semesters = request.request.semesters;
+    let mut checks = Vec::new();
+    let mut exceptions = Vec::new();
+
+    if undergraduate {
+        let first_exception = matches!(kind, "군입대" | "질병" | "임신·출산·육아");
+        let (passed, detail) = match request.registered_semesters {
+            None => (None, "registered_semesters 미입력: 입학 후 2학기 이내인지 확인 불가".to_string()),
+            Some(registered) if registered >= first_window => {
+                (Some(true), format!("입학 후 {registered}학기 등록: {first_window}학기 경과"))
+            }
+            Some(registered) if first_exception => {
+                exceptions.push(format!("입학 후 {first_window}학기 이내 휴학 예외: {kind} {first_citation}"));
+                (Some(true), format!("입학 후 {registered}학기 등록이지만 {kind} 휴학은 증빙으로 허가 가능"))
+            }
+            Some(registered) => (
+                Some(false),
+                format!("입학 후 {registered}학기 등록: {first_window}학기 이내에는 {kind} 휴학 불가"),
+            ),
+        };
+        if passed != Some(true) {
+            notes.push(
+                "외국인 학생이 세계보건기구 감염병 경보 5단계 이상 선포로 입국과 국외 수강이 모두 어려우면 입학 직후에도 \
+                 증빙으로 휴학 허가 가능: 외국인 여부는 입력에 없어 판정에 반영하지 않음 (학칙.pdf, p.6, 제27조 2항 4호)"
+                    .to_string(),
+            );
+        }
+        checks.push(check("입학 직후 휴학 제한", passed, detail, &first_citation));
+        if kind == "질병" {
+            notes.push("입학 직후 질병휴학 예외는 4주 이상 입원치료가 필요한 경우 (학칙.pdf, p.6, 제27조 2항 2호)".to_string());
+        }
+    }
+
+    if let Some((cap, citation, exception)) = consecutive_limit(kind, undergraduate, as_of) {
+        let run = request.consecutive_semesters + semesters;
+        let (passed, detail) = match cap {
+            None => {
+                exceptions.push(format!("연속 휴학 제한 예외: {kind} {citation}"));
+                (Some(true), format!("{kind} 휴학은 연속 학기 제한 없음"))
+            }
+            Some(cap) => {
+                if exception {
+                    exceptions.push(format!("연속 휴학 {cap}학기까지 예외: {kind} {citation}"));
+                }
+                (
+                    Some(run <= cap),
+                    format!("직전 연속 휴학 {}학기 + 신청 {semesters}학기 = {run}학기 (한도 {cap}학기)", request.consecutive_semesters),
+                )
+            }
+        };
+        if passed == Some(false) && undergraduate {
+            notes.push("학사과정은 총장이 필요하다고 인정하면 복학 절차 없이 휴학 연장 가능 (학칙.pdf, p.6, 제27조 5항 4호)".to_string());
+        }
+        checks.push(check("연속 휴학", passed, detail, &citation));
+    }
+
+    if kind == "창업" {
+        let total = used("창업") + semesters;
+        checks.push(check(
+            "창업휴학 한도",
+            Some(total <= startup_cap),
+            format!("기존 창업휴학 {}학기 + 신청 {semesters}학기 = {total}학기 (한도 {startup_cap}학기)", used("창업")),
+            &startup_citation,
+        ));
+        notes.push("창업휴학 세부사항은 학칙시행세칙 소관 (학칙.pdf, p.6, 제27조 4항)".to_string());
+    }
+
+    let added = counted(kind, used(kind) + semesters, undergraduate, as_of) - counted(kind, used(kind), undergraduate, as_of);
+    if added < semesters {
+        if let Some((_, citation)) = excluded_cap(kind, undergraduate, as_of) {
+            exceptions.push(format!("통산 휴학기간 산입 제외: {kind} {}학기 {citation}", semesters - added));
+        }
+    }
+    checks.push(check(
+        "통산 휴학",
+        Some(counted_total + added <= limit),
+        format!("통산 산입 {counted_total}학기 + 신청 산입 {added}학기 = {}학기 (한도 {limit}학기)", counted_total + added),
+        &limit_citation,
+    ));
+
+    let failed: Vec<&str> = checks
+        .iter()
+        .filter(|check| check["passed"] == json!(false))
+        .filter_map(|check| check["check"].as_str())
+        .collect();
+    let unknown: Vec<&str> = checks
+        .iter()
+        .filter(|check| check["passed"].is_null())
+        .filter_map(|check| check["check"].as_str())
+        .collect();
+    let (judgement, reason) = if !failed.is_empty() {
+        ("불가", format!("미충족: {}", failed.join(", ")))
+    } else if !unknown.is_empty() {
+        ("판정 불가", format!("확인 필요: {}", unknown.join(", ")))
+    } else {
+        ("가능", format!("{kind} 휴학 {semesters}학기 신청이 제27조 한도 안에 있음"))
+    };
+
+    if !request.request_given {
+        notes.push("request 미입력: 일반휴학 1학기 신청으로 가정".to_string());
+    }
+    notes.push("휴학은 매학기 소정기간 내 신청해 총장의 허가를 받아야 함 (학칙.pdf, p.6, 제27조 1항)".to_string());
+
+    json!({
+        "judgement": judgement,
+        "reason": reason,
+        "admission_category": request.category,
+        "request": { "type": kind, "semesters": semesters },
+        "quota": {
+            "limit": limit,
+            "counted": counted_total,
+            "remaining": (limit - counted_total).max(0),
+            "remaining_after_request": (limit - counted_total - added).max(0),
+            "excluded": excluded,
+            "citation": limit_citation,
+        },
+        "usage": usage
+            .iter()
+            .map(|(kind, total)| json!({ "type": kind, "semesters": total }))
+            .collect::<Vec<_>>(),
+        "checks": checks,
+        "exceptions": exceptions,
+        "citation": "(학칙.pdf, p.6, 제27조)",
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leave_request(
+        admission_year: i64,
+        history: &[(&'static str, i64)],
+        request: (&'static str, i64),
+    ) -> LeaveRequest<'static> {
+        LeaveRequest {
+            category: "신입학",
+            admission_year: Some(admission_year),
+            registered_semesters: Some(4),
+            consecutive_semesters: 0,
+            history: history
+                .iter()
+                .map(|(kind, semesters)| LeaveRecord { kind, semesters: *semesters })
+                .collect(),
+            request: LeaveRecord { kind: request.0, semesters: request.1 },
+            request_given: true,
+        }
+    }
+
+    #[test]
+    fn excluded_leaves_do_not_count_toward_quota() {
+        let history = [("군입대", 4), ("창업", 2), ("임신·출산·육아", 5), ("일반", 2)];
+        let result = evaluate_leave(&leave_request(2024, &history, ("일반", 1)), None);
+        assert_eq!(result["judgement"], "가능");
+        assert_eq!(result["quota"]["limit"], 6);
+        assert_eq!(result["quota"]["counted"], 3);
+        assert_eq!(result["quota"]["remaining_after_request"], 2);
+        let excluded: Vec<(&str, i64)> = result["quota"]["excluded"]
+            .as_array()
+            .expect("excluded")
+            .iter()
+            .map(|item| (item["type"].as_str().unwrap_or_default(), item["excluded"].as_i64().unwrap_or_default()))
+            .collect();
+        assert_eq!(excluded, vec![("군입대", 4), ("창업", 2), ("임신·출산·육아", 4)]);
+    }
+
+    #[test]
+    fn entrants_through_2022_keep_ten_semester_limit() {
+        let history = [("일반", 2), ("일반", 2), ("일반", 2)];
+        let legacy = evaluate_leave(&leave_request(2022, &history, ("일반", 2)), None);
+        assert_eq!(legacy["quota"]["limit"], 10);
+        assert_eq!(legacy["judgement"], "가능");
+
+        let current = evaluate_leave(&leave_request(2023, &history, ("일반", 2)), None);
+        assert_eq!(current["quota"]["limit"], 6);
+        assert_eq!(current["judgement"], "불가");
+    }
+
+    #[test]
+    fn first_semester_leave_mentions_the_international_student_exception() {
+        let mut request = leave_request(2025, &[], ("일반", 1));
+        request.registered_semesters = Some(1);
+        let result = evaluate_leave(&request, None);
+        assert_eq!(result["judgement"], "불가");
+        assert!(result["notes"]
+            .as_array()
+            .expect("notes")
+            .iter()
+            .any(|note| note.as_str().unwrap_or_default().contains("제27조 2항 4호")));
+
+        let result = evaluate_leave(&leave_request(2025, &[], ("일반", 1)), None);
+        assert!(!result["notes"].to_string().contains("제27조 2항 4호"));
+    }
+
+    #[test]
+    fn leave_input_errors_name_the_offending_field() {
+        let history = |value: Value| parse_history(json!({ "leaves": value }).as_object().expect("object")).err();
+        assert_eq!(history(json!({ "type": "일반" })).as_deref(), Some("'leaves' must be an array"));
+        assert_eq!(history(json!(["일반"])).as_deref(), Some("leaves[0] must be an object"));
+        assert_eq!(
+            history(json!([{ "type": "일반", "semesters": 2 }, { "type": "어학연수" }])),
+            Some(format!("leaves[1]: 'type' must be one of {}", LEAVE_TYPES.join("/")))
+        );
+        assert_eq!(
+            history(json!([{ "type": "질병", "semesters": 0 }])).as_deref(),
+            Some("leaves[0]: 'semesters' must be a positive integer")
+        );
+        let request = |value: Value| parse_request(json!({ "request": value }).as_object().expect("object")).err();
+        assert_eq!(request(json!("일반")).as_deref(), Some("'request' must be an object"));
+        assert_eq!(
+            request(json!({ "type": "일반", "semesters": -1 })).as_deref(),
+            Some("request: 'semesters' must be a positive integer")
+        );
+        assert!(parse_history(&Map::new()).expect("no leaves").is_empty());
+    }
+}
@@ -3,9 +3,45 @@ use regex::Regex;
 use serde_json::{json, Map, Value};
 use worker::*;
 
+mod accreditation;
+mod catalog;
+mod checklist;
+mod date;
+mod diff;
+mod early;
+mod elective;
+mod enrollment;
+mod front_matter;
+mod gaps;
+mod gpa;
+mod hangul;
+mod leave;
+mod liberal;
+mod load;
+mod planner;
+mod programs;
+mod refund;
+mod retake;
+mod scenarios;
+mod sources;
+mod standing;
+mod table;
+mod transcript;
+mod warning;
+
+use catalog::{PartMatch, Scope};
+use date::CivilDate;
+
 const SERVER_NAME: &str = "Soongsil MCP Worker (Rust)";
 const SERVER_VERSION: &str = "0.1.0";
 const DEFAULT_PROTOCOL_VERSION: &str = "2025-03-26";
+const NEAR_TIE_MARGIN: i32 = 2;
+const MAJOR_TYPES: &[&str] = &[
+    "단일전공자",
+    "부전공자",
+    "복수전공자(주전공)",
+    "복수전공자(복수전공)",
+];
 
 const PATH_HAKCHIK: &str = "/knowledge/normalized-md/학칙.md";
 const PATH_CREDIT_SYSTEM: &str = "/knowledge/normalized-md/학점 이수 체계.md";
@@ -17,6 +53,7 @@ const PATH_LAW_TOPIC: &str = "/references/law-topic-index.md";
 const PATH_LAW_ARTICLES: &str = "/references/law-articles.md";
 const PATH_LAW_NUMERIC: &str = "/references/law-numeric-rules.md";
 const PATH_SOURCE_MAP: &str = "/references/source-map.md";
+const PATH_PROGRAM_CATALOG: &str = "/references/program-catalog.md";
 
 const FILE_HAKCHIK: &str = include_str!("../../../knowledge/normalized-md/학칙.md");
 const FILE_CREDIT_SYSTEM: &str = include_str!("../../../knowledge/normalized-md/학점 이수 체계.md");
@@ -28,6 +65,21 @@ const FILE_LAW_TOPIC: &str = include_str!("../../../mcp/soongsil-mcp/references/
 const FILE_LAW_ARTICLES: &str = include_str!("../../../mcp/soongsil-mcp/references/law-articles.md");
 const FILE_LAW_NUMERIC: &str = include_str!("../../../mcp/soongsil-mcp/references/law-numeric-rules.md");
 const FILE_SOURCE_MAP: &str = include_str!("../../../mcp/soongsil-mcp/references/source-map.md");
+const FILE_PROGRAM_CATALOG: &str = include_str!("../../../mcp/soongsil-mcp/references/program-catalog.md");
+
+const SNAPSHOTS: &[Snapshot] = &[
+    Snapshot { path: PATH_HAKCHIK, effective_from: "2024-03-01", text: FILE_HAKCHIK },
+    Snapshot { path: PATH_CREDIT_SYSTEM, effective_from: "2024-03-01", text: FILE_CREDIT_SYSTEM },
+    Snapshot { path: PATH_LIBERAL_REQUIRED, effective_from: "2023-03-01", text: FILE_LIBERAL_REQUIRED },
+    Snapshot { path: PATH_LIBERAL_ELECTIVE, effective_from: "2023-03-01", text: FILE_LIBERAL_ELECTIVE },
+    Snapshot { path: PATH_CALENDAR, effective_from: "2026-01-01", text: FILE_CALENDAR },
+    Snapshot { path: PATH_HAKCHIK_RAW, effective_from: "2024-03-01", text: FILE_HAKCHIK_RAW },
+    Snapshot { path: PATH_LAW_TOPIC, effective_from: "2024-03-01", text: FILE_LAW_TOPIC },
+    Snapshot { path: PATH_LAW_ARTICLES, effective_from: "2024-03-01", text: FILE_LAW_ARTICLES },
+    Snapshot { path: PATH_LAW_NUMERIC, effective_from: "2024-03-01", text: FILE_LAW_NUMERIC },
+    Snapshot { path: PATH_SOURCE_MAP, effective_from: "2023-03-01", text: FILE_SOURCE_MAP },
+    Snapshot { path: PATH_PROGRAM_CATALOG, effective_from: "2024-03-01", text: FILE_PROGRAM_CATALOG },
+];
 
 const NORMALIZED_FILES: &[&str] = &[
     "/knowledge/normalized-md/README.md",
@@ -50,6 +102,7 @@ const REFERENCE_FILES: &[&str] = &[
     "/references/normalization-checklist.md",
     "/references/normalization-rules.md",
     "/references/normalization-template.md",
+    "/references/program-catalog.md",
     "/references/qa-checklists.md",
     "/references/source-map.md",
 ];
@@ -59,9 +112,22 @@ static TERM_RE: Lazy<Regex> =
 static MONTH_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(\d{2})-\d{2}").expect("MONTH_RE compile failure"));
 
+struct Snapshot {
+    path: &'static str,
+    effective_from: &'static str,
+    text: &'static str,
+}
+
+impl Snapshot {
+    fn effective_date(&self) -> CivilDate {
+        CivilDate::parse_iso(self.effective_from).expect("SNAPSHOTS effective_from must be a YYYY-MM-DD date")
+    }
+}
+
 #[derive(Clone)]
 struct SearchHit {
     file: &'static str,
+    snapshot: &'static str,
     line: usize,
     page: Option<String>,
     snippet: String,
@@ -75,6 +141,32 @@ struct CreditRow {
     values: Map<String, Value>,
 }
 
+#[derive(Clone)]
+struct CreditMatch {
+    row: CreditRow,
+    confidence: &'static str,
+    reason: String,
+    candidates: Vec<Value>,
+}
+
+impl CreditMatch {
+    fn summary(&self) -> Value {
+        json!({
+            "confidence": self.confidence,
+            "reason": self.reason,
+            "candidates": self.candidates,
+        })
+    }
+}
+
+struct CreditTotals {
+    liberal_required: i64,
+    liberal_elective: i64,
+    major_basic: i64,
+    major: i64,
+    total: i64,
+}
+
 #[derive(Clone)]
 struct CalendarRow {
     period: String,
@@ -95,20 +187,41 @@ fn rpc_failure(code: i64, message: impl Into<String>) -> RpcFailure {
     }
 }
 
-fn get_file_text(path: &str) -> Option<&'static str> {
-    match path {
-        PATH_HAKCHIK => Some(FILE_HAKCHIK),
-        PATH_CREDIT_SYSTEM => Some(FILE_CREDIT_SYSTEM),
-        PATH_LIBERAL_REQUIRED => Some(FILE_LIBERAL_REQUIRED),
-        PATH_LIBERAL_ELECTIVE => Some(FILE_LIBERAL_ELECTIVE),
-        PATH_CALENDAR => Some(FILE_CALENDAR),
-        PATH_HAKCHIK_RAW => Some(FILE_HAKCHIK_RAW),
-        PATH_LAW_TOPIC => Some(FILE_LAW_TOPIC),
-        PATH_LAW_ARTICLES => Some(FILE_LAW_ARTICLES),
-        PATH_LAW_NUMERIC => Some(FILE_LAW_NUMERIC),
-        PATH_SOURCE_MAP => Some(FILE_SOURCE_MAP),
-        _ => None,
-    }
+fn resolve_snapshot(path: &str, as_of: Option<&str>) -> Option<&'static Snapshot> {
+    let as_of = as_of.and_then(CivilDate::parse);
+    let revisions = || SNAPSHOTS.iter().filter(|snapshot| snapshot.path == path);
+    revisions()
+        .filter(|snapshot| as_of.map(|date| snapshot.effective_date() <= date).unwrap_or(true))
+        .max_by_key(|snapshot| snapshot.effective_date())
+        .or_else(|| revisions().min_by_key(|snapshot| snapshot.effective_date()))
+}
+
+fn get_file_text(path: &str, as_of: Option<&str>) -> Option<&'static str> {
+    resolve_snapshot(path, as_of).map(|snapshot| snapshot.text)
+}
+
+fn fallback_snapshots(as_of: &str) -> Vec<Value> {
+    let Some(date) = CivilDate::parse(as_of) else {
+        return vec![];
+    };
+    let mut paths: Vec<&'static str> = SNAPSHOTS.iter().map(|snapshot| snapshot.path).collect();
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+        .into_iter()
+        .filter_map(|path| resolve_snapshot(path, Some(as_of)))
+        .filter(|snapshot| snapshot.effective_date() > date)
+        .map(|snapshot| json!({ "file": snapshot.path, "effective_from": snapshot.effective_from }))
+        .collect()
+}
+
+fn snapshot_warning(as_of: &str, fallback: Vec<Value>) -> Value {
+    json!({
+        "as_of": as_of,
+        "message": format!("as_of {as_of} 시점에 시행 중이던 개정본이 없는 문서는 가장 이른 스냅샷으로 판단함: 해당 시점 원문과 다를 수 있음"),
+        "fallback_snapshots": fallback,
+        "required_action": "해당 시점 개정본을 knowledge/snapshots/<시행일>/에 추가하고 SNAPSHOTS에 등록",
+    })
 }
 
 fn category_paths(category: &str) -> Vec<&'static str> {
@@ -205,6 +318,19 @@ fn classify(question: &str) -> (String, Vec<String>) {
     }
 }
 
+fn recommended_tools(question: &str) -> Vec<&'static str> {
+    let tools: [(&[&str], &str); 3] = [
+        (&["휴학", "창업휴학", "군휴학"], "ssu_evaluate_leave"),
+        (&["학사경고", "연속 경고"], "ssu_evaluate_academic_standing"),
+        (&["재수강", "재수강 분반"], "ssu_analyze_retake"),
+    ];
+    tools
+        .iter()
+        .filter(|(keywords, _)| keywords.iter().any(|keyword| question.contains(keyword)))
+        .map(|(_, tool)| *tool)
+        .collect()
+}
+
 fn extract_terms(question: &str) -> Vec<String> {
     let mut out = Vec::new();
     for mat in TERM_RE.find_iter(question) {
@@ -250,6 +376,23 @@ fn parse_page_heading(line: &str) -> Option<String> {
     }
 }
 
+fn article_citation(basis: &str) -> String {
+    match basis.rsplit_once(", ") {
+        Some((article, page)) => format!("(학칙.pdf, {page}, {article})"),
+        None => format!("(학칙.pdf, {basis})"),
+    }
+}
+
+fn numeric_rule(label: &str, as_of: Option<&str>) -> Option<(String, String)> {
+    table::extract_tables(PATH_LAW_NUMERIC, as_of)
+        .into_iter()
+        .filter(|table| table.headers.len() == 3 && table.headers[2] == "근거")
+        .find_map(|table| {
+            let row = table.rows.iter().find(|row| row.cells.first().map(String::as_str) == Some(label))?;
+            Some((row.cells.get(1)?.clone(), article_citation(row.cells.get(2)?)))
+        })
+}
+
 fn citation_hint(path: &str, page: Option<&str>) -> String {
     let name = path.rsplit('/').next().unwrap_or(path);
     match name {
@@ -260,9 +403,9 @@ fn citation_hint(path: &str, page: Option<&str>) -> String {
                 "(학칙.pdf, 페이지 확인 필요)".to_string()
             }
         }
-        "학점 이수 체계.md" => "(학점 이수 체계.pdf, p.1)".to_string(),
-        "교양 필수.md" => "(교양 필수.pdf, p.1~2)".to_string(),
-        "교양 선택.md" => "(교양 선택.pdf, p.1~4)".to_string(),
+        "학점 이수 체계.md" => format!("(학점 이수 체계.pdf, p.{})", page.unwrap_or("1")),
+        "교양 필수.md" => format!("(교양 필수.pdf, p.{})", page.unwrap_or("1~2")),
+        "교양 선택.md" => format!("(교양 선택.pdf, p.{})", page.unwrap_or("1~4")),
         "학사 일정.md" => {
             if let Some(page) = page {
                 format!("(학사 일정.pdf, p.{page})")
@@ -274,7 +417,12 @@ fn citation_hint(path: &str, page: Option<&str>) -> String {
     }
 }
 
-fn search_in_file(path: &'static str, terms: &[String], max_hits: usize) -> Vec<SearchHit> {
+fn search_in_file(
+    path: &'static str,
+    terms: &[String],
+    max_hits: usize,
+    as_of: Option<&str>,
+) -> Vec<SearchHit> {
     if max_hits == 0 || terms.is_empty() {
         return vec![];
     }
@@ -282,8 +430,8 @@ fn search_in_file(path: &'static str, terms: &[String], max_hits: usize) -> Vec<
     let mut hits = Vec::new();
     let mut current_page: Option<String> = None;
 
-    if let Some(text) = get_file_text(path) {
-        for (idx, line) in text.lines().enumerate() {
+    if let Some(snapshot) = resolve_snapshot(path, as_of) {
+        for (idx, line) in snapshot.text.lines().enumerate() {
             if let Some(page) = parse_page_heading(line) {
                 current_page = Some(page);
             }
@@ -306,6 +454,7 @@ fn search_in_file(path: &'static str, terms: &[String], max_hits: usize) -> Vec<
 
             hits.push(SearchHit {
                 file: path,
+                snapshot: snapshot.effective_from,
                 line: idx + 1,
                 page: current_page.clone(),
                 snippet: candidate.chars().take(240).collect(),
@@ -332,44 +481,25 @@ fn split_pipe_row(line: &str) -> Vec<String> {
         .collect()
 }
 
-fn load_credit_rows() -> Vec<CreditRow> {
-    let lines: Vec<&str> = FILE_CREDIT_SYSTEM.lines().collect();
-    let mut start = None;
-    for (idx, line) in lines.iter().enumerate() {
-        if line.trim().starts_with("| 대학 | 학과/학부 |") {
-            start = Some(idx);
-            break;
-        }
-    }
-    let Some(start_idx) = start else {
+fn load_credit_rows(as_of: Option<&str>) -> Vec<CreditRow> {
+    let Some(table) = table::find_table(PATH_CREDIT_SYSTEM, as_of, &["대학", "학과/학부"]) else {
         return vec![];
     };
 
-    let headers = split_pipe_row(lines[start_idx]);
-    let mut rows = Vec::new();
-
-    for line in lines.iter().skip(start_idx + 2) {
-        let stripped = line.trim();
-        if !stripped.starts_with('|') {
-            if !rows.is_empty() {
-                break;
-            }
-            continue;
-        }
-
-        let cells = split_pipe_row(stripped);
-        if cells.len() != headers.len() {
-            continue;
-        }
-
-        let mut row = Map::new();
-        for (header, value) in headers.iter().zip(cells.iter()) {
-            row.insert(header.clone(), Value::String(value.clone()));
-        }
-        rows.push(CreditRow { values: row });
-    }
+    table
+        .rows
+        .iter()
+        .filter(|row| !table.is_ragged(row))
+        .map(|row| CreditRow {
+            values: table.row_map(row),
+        })
+        .collect()
+}
 
-    rows
+fn skipped_credit_rows(as_of: Option<&str>) -> Vec<Value> {
+    table::find_table(PATH_CREDIT_SYSTEM, as_of, &["대학", "학과/학부"])
+        .map(|table| table.skipped_rows())
+        .unwrap_or_default()
 }
 
 fn row_string(row: &CreditRow, key: &str) -> String {
@@ -380,81 +510,296 @@ fn row_string(row: &CreditRow, key: &str) -> String {
         .to_string()
 }
 
-fn match_credit_row(rows: &[CreditRow], college: &str, department: &str) -> Option<CreditRow> {
-    let college = college.trim();
-    let department = department.trim();
-    let mut best_score = -1_i32;
-    let mut best_row: Option<CreditRow> = None;
+fn score_credit_row(
+    row: &CreditRow,
+    program: &catalog::Program,
+) -> Option<(i32, &'static str, &'static str)> {
+    let row_college = row_string(row, "대학");
+    let row_department = row_string(row, "학과/학부");
+    if row_college.is_empty() {
+        return None;
+    }
+    let college = program.college.as_str();
+    let department = program.department.as_str();
 
-    for row in rows {
-        let row_college = row_string(row, "대학");
-        let row_department = row_string(row, "학과/학부");
-        let mut score = 0_i32;
+    let (college_score, college_match) = if row_college == college {
+        (20, "exact")
+    } else if !college.is_empty() && row_college.contains(college) {
+        (12, "contains")
+    } else {
+        return None;
+    };
 
-        if row_college.is_empty() {
-            continue;
-        }
+    let scope = catalog::row_scope(&row_department);
+    let part = scope.best_match(department, program.major.as_deref());
+    let (department_score, department_match) = match scope {
+        Scope::All => (8, "전체 fallback"),
+        Scope::Except(_) => match part {
+            PartMatch::Full => return None,
+            PartMatch::Partial => (10, "외 (전공 미입력)"),
+            PartMatch::None if program.known => (18, "외 (카탈로그)"),
+            PartMatch::None => (6, "외 fallback"),
+        },
+        Scope::Listed(_) => match part {
+            PartMatch::Full => (20, "exact"),
+            PartMatch::Partial => (10, "전공 미입력"),
+            PartMatch::None if department.is_empty() => (4, "학과 미입력"),
+            PartMatch::None if !program.known && row_department.contains(department) => (14, "contains"),
+            PartMatch::None => (0, "불일치"),
+        },
+    };
 
-        if row_college == college {
-            score += 20;
-        } else if !college.is_empty() && row_college.contains(college) {
-            score += 12;
-        } else {
-            continue;
-        }
+    Some((college_score + department_score, college_match, department_match))
+}
+
+fn match_credit_row(rows: &[CreditRow], program: &catalog::Program) -> Option<CreditMatch> {
+    let mut scored: Vec<(i32, &'static str, &'static str, &CreditRow)> = rows
+        .iter()
+        .filter_map(|row| {
+            score_credit_row(row, program)
+                .map(|(score, college_match, department_match)| (score, college_match, department_match, row))
+        })
+        .collect();
+    scored.sort_by_key(|(score, ..)| std::cmp::Reverse(*score));
+
+    let (best_score, college_match, department_match, best_row) = *scored.first()?;
+    let near_tied = scored
+        .iter()
+        .skip(1)
+        .any(|(score, ..)| best_score - score <= NEAR_TIE_MARGIN);
 
-        if row_department == department {
-            score += 20;
-        } else if !department.is_empty() && row_department.contains(department) {
-            score += 14;
-        } else if row_department.contains("전체") {
-            score += 8;
-        } else if row_department.contains("외") {
-            score += 6;
-        } else if department.is_empty() {
-            score += 4;
+    let confidence = if near_tied {
+        "low"
+    } else {
+        match department_match {
+            "exact" | "전체 fallback" | "외 (카탈로그)" => "high",
+            "contains" | "전공 미입력" | "외 (전공 미입력)" | "외 fallback" => "medium",
+            _ => "low",
         }
+    };
 
-        if score > best_score {
-            best_score = score;
-            best_row = Some(row.clone());
+    let candidates = scored
+        .iter()
+        .filter(|(score, ..)| best_score - score <= NEAR_TIE_MARGIN)
+        .map(|(score, college_match, department_match, row)| {
+            json!({
+                "대학": row_string(row, "대학"),
+                "학과/학부": row_string(row, "학과/학부"),
+                "score": score,
+                "college_match": college_match,
+                "department_match": department_match,
+            })
+        })
+        .collect();
+
+    Some(CreditMatch {
+        row: best_row.clone(),
+        confidence,
+        reason: format!("대학 {college_match}, 학과 {department_match}"),
+        candidates,
+    })
+}
+
+fn known_colleges(rows: &[CreditRow]) -> Vec<String> {
+    let mut colleges: Vec<String> = Vec::new();
+    for row in rows {
+        let college = row_string(row, "대학");
+        if !college.is_empty() && !colleges.contains(&college) {
+            colleges.push(college);
         }
     }
+    colleges
+}
 
-    best_row
+fn judgement_rank(judgement: &str) -> u8 {
+    match judgement {
+        "가능" => 2,
+        "판정 불가" => 1,
+        _ => 0,
+    }
 }
 
-fn parse_calendar_rows() -> Vec<CalendarRow> {
-    let mut rows = Vec::new();
-    let mut page = String::new();
-    let mut in_table = false;
+fn evaluate_credit_totals(
+    college: &str,
+    department: &str,
+    major_type: &str,
+    earned: &CreditTotals,
+    admission_year: Option<i64>,
+    as_of: Option<&str>,
+) -> Value {
+    let Some(snapshot) = resolve_snapshot(PATH_CREDIT_SYSTEM, as_of) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": format!("{} 시점에 적용되는 학점 이수 체계 스냅샷이 없음", as_of.unwrap_or_default()),
+            "citation": "(학점 이수 체계.pdf, p.1)"
+        });
+    };
+    let rows = load_credit_rows(as_of);
+    let skipped_rows = skipped_credit_rows(as_of);
+    let program = catalog::resolve(college, department, as_of);
+    let Some(matched) = match_credit_row(&rows, &program) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "학점 이수 체계 표에서 일치하는 대학/학과 행을 찾지 못함",
+            "program": program.summary(),
+            "known_colleges": known_colleges(&rows),
+            "skipped_rows": skipped_rows,
+            "citation": "(학점 이수 체계.pdf, p.1)"
+        });
+    };
+    let row = matched.row.clone();
+
+    if !MAJOR_TYPES.contains(&major_type) {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "major_type은 단일전공자/부전공자/복수전공자(주전공)/복수전공자(복수전공) 중 하나여야 함",
+            "citation": "(학점 이수 체계.pdf, p.1)"
+        });
+    }
 
-    for line in FILE_CALENDAR.lines() {
-        let stripped = line.trim();
-        if let Some(p) = parse_page_heading(stripped) {
-            page = p;
-        }
+    let raw_major_requirement = row_string(&row, major_type);
+    let major_requirement = extract_first_int(&raw_major_requirement);
+    if raw_major_requirement.trim() == "불허" {
+        return json!({
+            "judgement": "불가",
+            "reason": format!("{major_type} 경로가 해당 학과에서 불허됨"),
+            "matched_rule": row.values,
+            "citation": "(학점 이수 체계.pdf, p.1)"
+        });
+    }
 
-        if stripped.starts_with("| 기간 | 일정 |") {
-            in_table = true;
-            continue;
-        }
-        if in_table && stripped.starts_with("| --- | --- |") {
-            continue;
-        }
-        if in_table && stripped.starts_with('|') {
-            let cells = split_pipe_row(stripped);
-            if cells.len() >= 2 {
-                rows.push(CalendarRow {
-                    period: cells[0].clone(),
-                    event: cells[1].clone(),
-                    page: page.clone(),
-                });
+    let cohort = admission_year.map(|year| liberal::cohort_requirements(year, as_of));
+    let req_liberal_required = match &cohort {
+        Some(cohort) => cohort.liberal_required,
+        None => Some(extract_first_int(&row_string(&row, "교양필수")).unwrap_or(19)),
+    };
+    let req_liberal_elective = match &cohort {
+        Some(cohort) if !cohort.elective_by_credits => None,
+        _ => Some(extract_first_int(&row_string(&row, "교양선택")).unwrap_or(9)),
+    };
+    let req_major_basic = extract_first_int(&row_string(&row, "전공기초")).unwrap_or(0);
+    let req_total = extract_first_int(&row_string(&row, "졸업학점")).unwrap_or(133);
+    let req_major = major_requirement.unwrap_or(0);
+
+    let mut gap = Map::new();
+    gap.insert(
+        "교양필수".to_string(),
+        json!(req_liberal_required.map(|req| (req - earned.liberal_required).max(0))),
+    );
+    gap.insert(
+        "교양선택".to_string(),
+        json!(req_liberal_elective.map(|req| (req - earned.liberal_elective).max(0))),
+    );
+    gap.insert(
+        "전공기초".to_string(),
+        json!((req_major_basic - earned.major_basic).max(0)),
+    );
+    gap.insert(
+        major_type.to_string(),
+        json!((req_major - earned.major).max(0)),
+    );
+    gap.insert("졸업학점".to_string(), json!((req_total - earned.total).max(0)));
+
+    let total_gap: i64 = gap.values().filter_map(Value::as_i64).sum();
+    let mut unverified = Vec::new();
+    if req_liberal_required.is_none() {
+        unverified.push("교양필수");
+    }
+    if req_liberal_elective.is_none() {
+        unverified.push("교양선택");
+    }
+    let judgement = if total_gap > 0 {
+        "불가"
+    } else if unverified.is_empty() {
+        "가능"
+    } else {
+        "판정 불가"
+    };
+
+    let mut required = Map::new();
+    required.insert("교양필수".to_string(), json!(req_liberal_required));
+    required.insert("교양선택".to_string(), json!(req_liberal_elective));
+    required.insert("전공기초".to_string(), json!(req_major_basic));
+    required.insert(major_type.to_string(), json!(req_major));
+    required.insert("졸업학점".to_string(), json!(req_total));
+
+    let mut earned_credits = Map::new();
+    earned_credits.insert("교양필수".to_string(), json!(earned.liberal_required));
+    earned_credits.insert("교양선택".to_string(), json!(earned.liberal_elective));
+    earned_credits.insert("전공기초".to_string(), json!(earned.major_basic));
+    earned_credits.insert(major_type.to_string(), json!(earned.major));
+    earned_credits.insert("졸업학점".to_string(), json!(earned.total));
+
+    let mut notes = vec![
+        "교양필수/교양선택의 세부 과목 충족 여부는 교양 필수/선택 문서로 추가 확인 필요".to_string(),
+        "최종 졸업판정은 학칙 졸업요건 조문과 함께 검증 권장".to_string(),
+    ];
+    if matched.confidence != "high" {
+        notes.push(format!(
+            "학점 이수 체계 행 매칭 신뢰도 {} ({}): row_match.candidates 확인 필요",
+            matched.confidence, matched.reason
+        ));
+    }
+    if !skipped_rows.is_empty() {
+        notes.push(format!(
+            "학점 이수 체계 표에서 칸 수가 맞지 않는 행 {}개를 매칭에서 제외: skipped_rows 확인 필요",
+            skipped_rows.len()
+        ));
+    }
+    match &cohort {
+        None => notes.push(
+            "admission_year 미입력: 2023학년도 이후 입학자 기준(교양필수 19, 교양선택 BALANCE 9) 적용".to_string(),
+        ),
+        Some(cohort) => {
+            if cohort.liberal_required.is_none() {
+                notes.push(format!(
+                    "{}학년도 입학자 교양필수 편성학점이 교양 필수 문서에 없어 교양필수 gap을 계산하지 않음: 입학년도 교육과정 확인 필요",
+                    cohort.admission_year
+                ));
             }
+            if !cohort.elective_by_credits {
+                notes.push(format!(
+                    "{}학년도 입학자 교양선택은 학점이 아닌 영역/과목 기준이므로 ssu_check_liberal_elective로 확인 필요",
+                    cohort.admission_year
+                ));
+            }
+        }
+    }
+
+    json!({
+        "judgement": judgement,
+        "as_of": as_of,
+        "snapshot": snapshot.effective_from,
+        "matched_rule": row.values,
+        "program": program.summary(),
+        "row_match": matched.summary(),
+        "skipped_rows": skipped_rows,
+        "cohort": cohort.map(|cohort| cohort.summary),
+        "required": required,
+        "earned": earned_credits,
+        "gap": gap,
+        "unverified": unverified,
+        "citation": "(학점 이수 체계.pdf, p.1)",
+        "notes": notes
+    })
+}
+
+fn parse_calendar_rows(as_of: Option<&str>) -> Vec<CalendarRow> {
+    let mut rows = Vec::new();
+
+    for table in table::extract_tables(PATH_CALENDAR, as_of) {
+        if !table.has_columns(&["기간", "일정"]) {
             continue;
         }
-        if in_table && stripped.is_empty() {
-            in_table = false;
+        for row in &table.rows {
+            let (Some(period), Some(event)) = (table.cell(row, "기간"), table.cell(row, "일정")) else {
+                continue;
+            };
+            rows.push(CalendarRow {
+                period: period.to_string(),
+                event: event.to_string(),
+                page: table.page.clone().unwrap_or_default(),
+            });
         }
     }
 
@@ -468,16 +813,21 @@ fn extract_month(period: &str) -> Option<i64> {
         .and_then(|v| v.as_str().parse::<i64>().ok())
 }
 
-fn search_evidence_impl(question: &str, category: &str, max_hits: usize) -> Value {
+fn search_evidence_impl(
+    question: &str,
+    category: &str,
+    max_hits: usize,
+    as_of: Option<&str>,
+) -> Value {
     let terms = extract_terms(question);
     let paths = category_paths(category);
 
     let mut all_hits: Vec<SearchHit> = Vec::new();
     for path in paths {
-        all_hits.extend(search_in_file(path, &terms, max_hits));
+        all_hits.extend(search_in_file(path, &terms, max_hits, as_of));
     }
 
-    all_hits.sort_by(|a, b| b.score.cmp(&a.score));
+    all_hits.sort_by_key(|hit| std::cmp::Reverse(hit.score));
     let clipped = all_hits
         .into_iter()
         .take(if max_hits > 0 { max_hits } else { 12 })
@@ -486,25 +836,38 @@ fn search_evidence_impl(question: &str, category: &str, max_hits: usize) -> Valu
     let hits_json: Vec<Value> = clipped
         .iter()
         .map(|hit| {
-            json!({
+            let mut hit_json = json!({
                 "file": hit.file,
+                "snapshot": hit.snapshot,
                 "line": hit.line,
                 "page": hit.page,
                 "snippet": hit.snippet,
                 "matched_terms": hit.matched_terms,
                 "score": hit.score,
                 "citation_hint": hit.citation_hint,
-            })
+            });
+            if let Some(warning) = sources::staleness_warning(hit.file, as_of) {
+                hit_json["staleness_warning"] = Value::String(warning);
+            }
+            hit_json
         })
         .collect();
 
-    json!({
+    let mut result = json!({
         "question": question,
         "category": category,
+        "as_of": as_of,
         "search_terms": terms,
         "hits": hits_json,
         "citation_rule": "최종 답변은 반드시 (문서명.pdf, p.N) 형식으로 표기"
-    })
+    });
+    if let Some((gap, matched)) = gaps::find_gap(question, as_of) {
+        result["judgement"] = json!(gaps::GAP_JUDGEMENT);
+        result["known_gap"] = gaps::gap_summary(&gap);
+        result["gap_keywords"] = json!(matched);
+        result["required_action"] = json!(format!("{} 제공 필요", gap.required_source));
+    }
+    result
 }
 
 fn number_to_i64(value: &Value) -> Option<i64> {
@@ -542,10 +905,47 @@ fn required_i64(args: &Map<String, Value>, key: &str) -> std::result::Result<i64
         .ok_or_else(|| format!("'{key}' is required and must be an integer"))
 }
 
+fn required_count(args: &Map<String, Value>, key: &str) -> std::result::Result<i64, String> {
+    let value = required_i64(args, key)?;
+    if value < 0 {
+        return Err(format!("'{key}' must be zero or positive"));
+    }
+    Ok(value)
+}
+
+fn earned_totals(args: &Map<String, Value>) -> std::result::Result<CreditTotals, String> {
+    Ok(CreditTotals {
+        liberal_required: required_count(args, "earned_liberal_required")?,
+        liberal_elective: required_count(args, "earned_liberal_elective")?,
+        major_basic: required_count(args, "earned_major_basic")?,
+        major: required_count(args, "earned_major")?,
+        total: required_count(args, "earned_total")?,
+    })
+}
+
+fn optional_count(args: &Map<String, Value>, key: &str) -> std::result::Result<Option<i64>, String> {
+    match optional_i64(args, key) {
+        Some(value) if value < 0 => Err(format!("'{key}' must be zero or positive")),
+        value => Ok(value),
+    }
+}
+
 fn optional_string(args: &Map<String, Value>, key: &str) -> Option<String> {
     args.get(key).and_then(Value::as_str).map(str::to_string)
 }
 
+fn optional_date(
+    args: &Map<String, Value>,
+    key: &str,
+) -> std::result::Result<Option<String>, String> {
+    match optional_string(args, key) {
+        Some(value) => CivilDate::parse(&value)
+            .map(|date| Some(date.iso()))
+            .ok_or_else(|| format!("'{key}' must be a YYYY-MM-DD date string")),
+        None => Ok(None),
+    }
+}
+
 fn optional_i64(args: &Map<String, Value>, key: &str) -> Option<i64> {
     args.get(key).and_then(number_to_i64)
 }
@@ -554,6 +954,18 @@ fn optional_f64(args: &Map<String, Value>, key: &str) -> Option<f64> {
     args.get(key).and_then(number_to_f64)
 }
 
+fn string_list(args: &Map<String, Value>, key: &str) -> std::result::Result<Vec<String>, String> {
+    args.get(key)
+        .and_then(Value::as_array)
+        .and_then(|items| {
+            items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| format!("'{key}' is required and must be an array of strings"))
+}
+
 fn tool_content(payload: Value) -> Value {
     let text = serde_json::to_string_pretty(&payload).unwrap_or_else(|_| payload.to_string());
     json!({
@@ -581,17 +993,38 @@ fn tool_error_content(message: &str) -> Value {
 fn call_tool(
     name: &str,
     args: &Map<String, Value>,
+) -> std::result::Result<Value, String> {
+    let as_of = optional_date(args, "as_of")?;
+    let mut result = dispatch_tool(name, args)?;
+    if let Some(as_of) = as_of {
+        let fallback = fallback_snapshots(&as_of);
+        if !fallback.is_empty() {
+            if let Some(object) = result.as_object_mut() {
+                object.insert("snapshot_warning".to_string(), snapshot_warning(&as_of, fallback));
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn dispatch_tool(
+    name: &str,
+    args: &Map<String, Value>,
 ) -> std::result::Result<Value, String> {
     match name {
         "ssu_classify_request" => {
             let question = required_string(args, "question")?;
+            let as_of = optional_date(args, "as_of")?;
             let (category, matched_keywords) = classify(&question);
             let paths = category_paths(&category);
+            let known_gap = gaps::find_gap(&question, as_of.as_deref()).map(|(gap, _)| gaps::gap_summary(&gap));
             Ok(json!({
                 "question": question,
                 "category": category,
                 "matched_keywords": matched_keywords,
                 "recommended_paths": paths,
+                "recommended_tools": recommended_tools(&question),
+                "known_gap": known_gap,
             }))
         }
         "ssu_search_evidence" => {
@@ -599,13 +1032,23 @@ fn call_tool(
             let category = optional_string(args, "category")
                 .unwrap_or_else(|| classify(&question).0.to_string());
             let max_hits = optional_i64(args, "max_hits").unwrap_or(12).max(1) as usize;
-            Ok(search_evidence_impl(&question, &category, max_hits))
+            let as_of = optional_date(args, "as_of")?;
+            Ok(search_evidence_impl(&question, &category, max_hits, as_of.as_deref()))
         }
         "ssu_rule_brief" => {
             let question = required_string(args, "question")?;
             let max_hits = optional_i64(args, "max_hits").unwrap_or(10).max(1) as usize;
+            let as_of = optional_date(args, "as_of")?;
             let (category, matched_keywords) = classify(&question);
-            let evidence = search_evidence_impl(&question, &category, max_hits);
+            if let Some((gap, gap_keywords)) = gaps::find_gap(&question, as_of.as_deref()) {
+                let mut result = gaps::gap_result(&gap, &gap_keywords);
+                result["question"] = json!(question);
+                result["category"] = json!(category);
+                result["as_of"] = json!(as_of);
+                result["response_template"] = json!(["결론", "근거", "계산/비교", "불확실성"]);
+                return Ok(result);
+            }
+            let evidence = search_evidence_impl(&question, &category, max_hits, as_of.as_deref());
             let evidence_hits = evidence
                 .get("hits")
                 .cloned()
@@ -615,6 +1058,7 @@ fn call_tool(
                 "question": question,
                 "category": category,
                 "matched_keywords": matched_keywords,
+                "as_of": as_of,
                 "workflow": [
                     "1) normalized-md에서 후보 규정 탐색",
                     "2) law-topic-index/law-articles/law-numeric-rules 교차확인(학칙 질의 시)",
@@ -622,6 +1066,7 @@ fn call_tool(
                     "4) PDF 페이지 인용으로 최종 확정"
                 ],
                 "response_template": ["결론", "근거", "계산/비교", "불확실성"],
+                "recommended_tools": recommended_tools(&question),
                 "evidence": evidence_hits
             }))
         }
@@ -629,107 +1074,367 @@ fn call_tool(
             let college = required_string(args, "college")?;
             let department = optional_string(args, "department").unwrap_or_default();
             let major_type = required_string(args, "major_type")?;
-            let earned_liberal_required = required_i64(args, "earned_liberal_required")?;
-            let earned_liberal_elective = required_i64(args, "earned_liberal_elective")?;
-            let earned_major_basic = required_i64(args, "earned_major_basic")?;
-            let earned_major = required_i64(args, "earned_major")?;
-            let earned_total = required_i64(args, "earned_total")?;
-
-            let rows = load_credit_rows();
-            let Some(row) = match_credit_row(&rows, &college, &department) else {
-                return Ok(json!({
-                    "judgement": "판정 불가",
-                    "reason": "학점 이수 체계 표에서 일치하는 대학/학과 행을 찾지 못함",
-                    "citation": "(학점 이수 체계.pdf, p.1)"
-                }));
+            let earned = earned_totals(args)?;
+            let admission_year = optional_i64(args, "admission_year");
+            let as_of = optional_date(args, "as_of")?;
+            let mut result = evaluate_credit_totals(
+                &college,
+                &department,
+                &major_type,
+                &earned,
+                admission_year,
+                as_of.as_deref(),
+            );
+            let rank = |judgement: &Value| judgement_rank(judgement.as_str().unwrap_or_default());
+            let program = catalog::resolve(&college, &department, as_of.as_deref());
+            let mut accredited = None;
+            if args.get("accreditation").and_then(Value::as_bool) == Some(true) {
+                let input = accreditation::AccreditationInput {
+                    earned: accreditation::parse_area_credits(args, "accreditation_credits")?,
+                    required: accreditation::parse_area_credits(args, "accreditation_required")?,
+                    required_courses_completed: args
+                        .get("accreditation_required_courses_completed")
+                        .and_then(Value::as_bool),
+                };
+                let evaluated =
+                    accreditation::evaluate_accreditation(&program, admission_year, &input, as_of.as_deref());
+                let applicable = evaluated["applicable"] == true;
+                if applicable && rank(&evaluated["judgement"]) < rank(&result["judgement"]) {
+                    result["judgement"] = evaluated["judgement"].clone();
+                }
+                if let Some(notes) = result["notes"].as_array_mut() {
+                    notes.push(json!(if applicable {
+                        "accreditation=true: 공학교육인증 부족분은 졸업학점 gap과 별도로 accreditation.gap에 표시".to_string()
+                    } else {
+                        format!(
+                            "accreditation=true지만 인증 요건 미적용({}): 졸업 판정에 반영하지 않음",
+                            evaluated["reason"].as_str().unwrap_or_default()
+                        )
+                    }));
+                }
+                result["accreditation"] = evaluated.clone();
+                accredited = Some(evaluated);
+            }
+            let requirements = checklist::evaluate_checklist(args, &program, accredited.as_ref(), as_of.as_deref());
+            if rank(&requirements["judgement"]) < rank(&result["judgement"]) {
+                result["judgement"] = requirements["judgement"].clone();
+                if let Some(notes) = result["notes"].as_array_mut() {
+                    notes.push(json!(format!(
+                        "학점 외 졸업요건(제45조·제46조) {}: requirements 확인",
+                        requirements["reason"].as_str().unwrap_or_default()
+                    )));
+                }
+            }
+            result["requirements"] = requirements;
+            Ok(result)
+        }
+        "ssu_plan_graduation" => {
+            let college = required_string(args, "college")?;
+            let department = optional_string(args, "department").unwrap_or_default();
+            let major_type = required_string(args, "major_type")?;
+            let earned = earned_totals(args)?;
+            let track = match optional_string(args, "track") {
+                Some(raw) => Some(liberal::normalize_track(&raw).ok_or_else(|| {
+                    format!("'track' must be one of {}", liberal::TRACKS.join("/"))
+                })?),
+                None => None,
             };
-
-            let allowed_major_types = [
-                "단일전공자",
-                "부전공자",
-                "복수전공자(주전공)",
-                "복수전공자(복수전공)",
-            ];
-            if !allowed_major_types.contains(&major_type.as_str()) {
-                return Ok(json!({
-                    "judgement": "판정 불가",
-                    "reason": "major_type은 단일전공자/부전공자/복수전공자(주전공)/복수전공자(복수전공) 중 하나여야 함",
-                    "citation": "(학점 이수 체계.pdf, p.1)"
-                }));
+            let completed_courses = match args.get("completed_courses") {
+                Some(_) => string_list(args, "completed_courses")?,
+                None => vec![],
+            };
+            let next_semester = optional_string(args, "next_semester");
+            if let Some(label) = &next_semester {
+                if !matches!(load::parse_term(label), Some((_, "1학기" | "2학기"))) {
+                    return Err(format!("'next_semester' must be a regular term such as 2026-1 or 2026-2 (got '{label}')"));
+                }
             }
-
-            let raw_major_requirement = row_string(&row, &major_type);
-            let major_requirement = extract_first_int(&raw_major_requirement);
-            if raw_major_requirement.trim() == "불허" {
-                return Ok(json!({
-                    "judgement": "불가",
-                    "reason": format!("{major_type} 경로가 해당 학과에서 불허됨"),
-                    "matched_rule": row.values,
-                    "citation": "(학점 이수 체계.pdf, p.1)"
-                }));
+            let as_of = optional_date(args, "as_of")?;
+            let request = planner::PlanRequest {
+                college: college.trim(),
+                department: department.trim(),
+                major_type: &major_type,
+                earned: &earned,
+                admission_year: optional_i64(args, "admission_year"),
+                completed_semesters: required_count(args, "completed_semesters")?,
+                next_semester: next_semester.as_deref(),
+                credits_this_year: optional_count(args, "credits_this_year")?.unwrap_or(0),
+                semester_cap: optional_i64(args, "semester_cap"),
+                track,
+                completed_courses: &completed_courses,
+            };
+            Ok(planner::plan_graduation(&request, as_of.as_deref()))
+        }
+        "ssu_evaluate_early_graduation" => {
+            let college = required_string(args, "college")?;
+            let department = optional_string(args, "department").unwrap_or_default();
+            let major_type = required_string(args, "major_type")?;
+            let as_of = optional_date(args, "as_of")?;
+            let report = gpa::report_from_args(args, as_of.as_deref())?;
+            let gpa = optional_f64(args, "gpa")
+                .or_else(|| report.as_ref().and_then(|report| report.cumulative))
+                .ok_or("'gpa' or graded 'courses' is required")?;
+            let gpa = gpa::validate_gpa(gpa, as_of.as_deref())?;
+            let earned_total = required_count(args, "earned_total")?;
+            let category_keys = [
+                "earned_liberal_required",
+                "earned_liberal_elective",
+                "earned_major_basic",
+                "earned_major",
+            ];
+            let categories = if category_keys.iter().any(|key| args.contains_key(*key)) {
+                Some(CreditTotals {
+                    liberal_required: required_count(args, "earned_liberal_required")?,
+                    liberal_elective: required_count(args, "earned_liberal_elective")?,
+                    major_basic: required_count(args, "earned_major_basic")?,
+                    major: required_count(args, "earned_major")?,
+                    total: earned_total,
+                })
+            } else {
+                None
+            };
+            let program = catalog::resolve(&college, &department, as_of.as_deref());
+            let requirements = checklist::evaluate_checklist(args, &program, None, as_of.as_deref());
+            let request = early::EarlyRequest {
+                college: college.trim(),
+                department: department.trim(),
+                major_type: &major_type,
+                gpa,
+                registered_semesters: required_count(args, "registered_semesters")?,
+                earned_total,
+                categories,
+                admission_year: optional_i64(args, "admission_year"),
+                semester_cap: optional_i64(args, "semester_cap"),
+                requirements: &requirements,
+            };
+            let mut result = early::evaluate_early_graduation(&request, as_of.as_deref());
+            if let Some(report) = report {
+                result["gpa_calculation"] = report.value;
             }
-
-            let req_liberal_required = extract_first_int(&row_string(&row, "교양필수")).unwrap_or(19);
-            let req_liberal_elective = extract_first_int(&row_string(&row, "교양선택")).unwrap_or(9);
-            let req_major_basic = extract_first_int(&row_string(&row, "전공기초")).unwrap_or(0);
-            let req_total = extract_first_int(&row_string(&row, "졸업학점")).unwrap_or(133);
-            let req_major = major_requirement.unwrap_or(0);
-
-            let mut gap = Map::new();
-            gap.insert(
-                "교양필수".to_string(),
-                json!((req_liberal_required - earned_liberal_required).max(0)),
-            );
-            gap.insert(
-                "교양선택".to_string(),
-                json!((req_liberal_elective - earned_liberal_elective).max(0)),
-            );
-            gap.insert(
-                "전공기초".to_string(),
-                json!((req_major_basic - earned_major_basic).max(0)),
-            );
-            gap.insert(
-                major_type.clone(),
-                json!((req_major - earned_major).max(0)),
+            Ok(result)
+        }
+        "ssu_evaluate_multi_major" => {
+            let college = required_string(args, "college")?;
+            let department = optional_string(args, "department").unwrap_or_default();
+            let secondaries = programs::parse_secondary_programs(args)?;
+            let earned = earned_totals(args)?;
+            let admission_year = optional_i64(args, "admission_year");
+            let as_of = optional_date(args, "as_of")?;
+            Ok(programs::evaluate_programs(
+                college.trim(),
+                department.trim(),
+                &earned,
+                &secondaries,
+                admission_year,
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_compare_graduation_scenarios" => {
+            let current = scenarios::Target {
+                college: required_string(args, "college")?.trim().to_string(),
+                department: optional_string(args, "department").unwrap_or_default().trim().to_string(),
+            };
+            let alternatives = scenarios::parse_alternatives(args)?;
+            let major_types = scenarios::major_types_filter(args)?;
+            let earned = earned_totals(args)?;
+            let admission_year = optional_i64(args, "admission_year");
+            let as_of = optional_date(args, "as_of")?;
+            Ok(scenarios::compare_scenarios(
+                &current,
+                &alternatives,
+                &major_types,
+                &earned,
+                admission_year,
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_audit_transcript" => {
+            let college = required_string(args, "college")?;
+            let department = optional_string(args, "department").unwrap_or_default();
+            let major_type = required_string(args, "major_type")?;
+            let admission_year = optional_i64(args, "admission_year");
+            let as_of = optional_date(args, "as_of")?;
+            let courses = transcript::parse_courses(args)?;
+            let audit = transcript::audit(&courses, &major_type);
+
+            let mut result = evaluate_credit_totals(
+                &college,
+                &department,
+                &major_type,
+                &audit.totals,
+                admission_year,
+                as_of.as_deref(),
             );
-            gap.insert("졸업학점".to_string(), json!((req_total - earned_total).max(0)));
-
-            let total_gap: i64 = gap.values().filter_map(Value::as_i64).sum();
-            let judgement = if total_gap == 0 { "가능" } else { "불가" };
-
-            let mut required = Map::new();
-            required.insert("교양필수".to_string(), json!(req_liberal_required));
-            required.insert("교양선택".to_string(), json!(req_liberal_elective));
-            required.insert("전공기초".to_string(), json!(req_major_basic));
-            required.insert(major_type.clone(), json!(req_major));
-            required.insert("졸업학점".to_string(), json!(req_total));
-
-            let mut earned = Map::new();
-            earned.insert("교양필수".to_string(), json!(earned_liberal_required));
-            earned.insert("교양선택".to_string(), json!(earned_liberal_elective));
-            earned.insert("전공기초".to_string(), json!(earned_major_basic));
-            earned.insert(major_type.clone(), json!(earned_major));
-            earned.insert("졸업학점".to_string(), json!(earned_total));
+            result["transcript"] = audit.summary;
+            if let Some(notes) = result.get_mut("notes").and_then(Value::as_array_mut) {
+                notes.extend(audit.notes.into_iter().map(Value::String));
+            }
 
-            Ok(json!({
-                "judgement": judgement,
-                "matched_rule": row.values,
-                "required": required,
-                "earned": earned,
-                "gap": gap,
-                "citation": "(학점 이수 체계.pdf, p.1)",
-                "notes": [
-                    "교양필수/교양선택의 세부 과목 충족 여부는 교양 필수/선택 문서로 추가 확인 필요",
-                    "최종 졸업판정은 학칙 졸업요건 조문과 함께 검증 권장"
-                ]
-            }))
+            let elective_courses = transcript::elective_courses(&audit.counted);
+            if let (Some(year), false) = (admission_year, elective_courses.is_empty()) {
+                let areas = elective::check_elective_areas(&elective_courses, year, college.trim(), as_of.as_deref());
+                let credit_judgement = match result["judgement"].as_str().unwrap_or_default() {
+                    "판정 불가" if result["unverified"] == json!(["교양선택"]) => "가능",
+                    other => other,
+                };
+                let area_judgement = areas["judgement"].as_str().unwrap_or("판정 불가");
+                let judgement = if judgement_rank(area_judgement) < judgement_rank(credit_judgement) {
+                    area_judgement
+                } else {
+                    credit_judgement
+                };
+                result["judgement"] = json!(judgement);
+                result["unverified"] = if area_judgement == "판정 불가" { json!(["교양선택"]) } else { json!([]) };
+                result["liberal_elective_areas"] = areas;
+            }
+            Ok(result)
+        }
+        "ssu_check_liberal_required" => {
+            let completed = string_list(args, "completed_courses")?;
+            let raw_track = required_string(args, "track")?;
+            let track = liberal::normalize_track(&raw_track).ok_or_else(|| {
+                format!("'track' must be one of {}", liberal::TRACKS.join("/"))
+            })?;
+            let admission_year = required_i64(args, "admission_year")?;
+            let college = optional_string(args, "college").unwrap_or_default();
+            let department = optional_string(args, "department").unwrap_or_default();
+            let as_of = optional_date(args, "as_of")?;
+            Ok(liberal::check_required_courses(
+                &completed,
+                track,
+                college.trim(),
+                department.trim(),
+                admission_year,
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_check_liberal_elective" => {
+            let courses = elective::parse_elective_courses(args)?;
+            let admission_year = required_i64(args, "admission_year")?;
+            let college = optional_string(args, "college").unwrap_or_default();
+            let as_of = optional_date(args, "as_of")?;
+            Ok(elective::check_elective_areas(
+                &courses,
+                admission_year,
+                college.trim(),
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_evaluate_standing" => {
+            let credits = required_count(args, "cumulative_credits")?;
+            let semesters = required_count(args, "registered_semesters")?;
+            let college = optional_string(args, "college").unwrap_or_default();
+            let department = optional_string(args, "department").unwrap_or_default();
+            let admission_year = optional_i64(args, "admission_year");
+            let as_of = optional_date(args, "as_of")?;
+            Ok(standing::evaluate_standing(
+                credits,
+                semesters,
+                college.trim(),
+                department.trim(),
+                admission_year,
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_evaluate_academic_standing" => {
+            let level = match optional_string(args, "level") {
+                Some(raw) => Some(
+                    load::normalize_level(&raw)
+                        .ok_or_else(|| format!("'level' must be one of {}", load::LEVELS.join("/")))?,
+                ),
+                None => None,
+            };
+            let as_of = optional_date(args, "as_of")?;
+            let report = gpa::report_from_args(args, as_of.as_deref())?;
+            let records = match &report {
+                Some(report) if !args.contains_key("semesters") => warning::records_from_report(report),
+                _ => warning::parse_semester_records(args)?,
+            };
+            let mut result = warning::evaluate_academic_standing(&records, level, as_of.as_deref());
+            if let Some(report) = report {
+                result["gpa_calculation"] = report.value;
+            }
+            Ok(result)
+        }
+        "ssu_evaluate_leave" => {
+            let raw_category = required_string(args, "admission_category")?;
+            let category = leave::normalize_category(&raw_category).ok_or_else(|| {
+                format!("'admission_category' must be one of {}", leave::ADMISSION_CATEGORIES.join("/"))
+            })?;
+            let request = leave::parse_request(args)?;
+            let request = leave::LeaveRequest {
+                category,
+                admission_year: optional_i64(args, "admission_year"),
+                registered_semesters: optional_count(args, "registered_semesters")?,
+                consecutive_semesters: optional_count(args, "consecutive_leave_semesters")?.unwrap_or(0),
+                history: leave::parse_history(args)?,
+                request_given: request.is_some(),
+                request: request.unwrap_or(leave::LeaveRecord {
+                    kind: "일반",
+                    semesters: 1,
+                }),
+            };
+            let as_of = optional_date(args, "as_of")?;
+            Ok(leave::evaluate_leave(&request, as_of.as_deref()))
+        }
+        "ssu_track_enrollment" => {
+            let terms = enrollment::parse_terms(args)?;
+            let program = match optional_string(args, "program") {
+                Some(raw) => enrollment::normalize_program(&raw)
+                    .ok_or_else(|| format!("'program' must be one of {}", enrollment::PROGRAMS.join("/")))?,
+                None => "학사",
+            };
+            let college = optional_string(args, "college").unwrap_or_default();
+            let department = optional_string(args, "department").unwrap_or_default();
+            let as_of = optional_date(args, "as_of")?;
+            Ok(enrollment::track_enrollment(
+                &terms,
+                program,
+                college.trim(),
+                department.trim(),
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_validate_course_load" => {
+            let semesters = load::parse_semesters(args)?;
+            let level = match optional_string(args, "level") {
+                Some(raw) => load::normalize_level(&raw)
+                    .ok_or_else(|| format!("'level' must be one of {}", load::LEVELS.join("/")))?,
+                None => "학사",
+            };
+            let allowance = optional_string(args, "allowance").unwrap_or_else(|| "기본".to_string());
+            if !load::GRADUATE_ALLOWANCES.contains(&allowance.trim()) {
+                return Err(format!(
+                    "'allowance' must be one of {}",
+                    load::GRADUATE_ALLOWANCES.join("/")
+                ));
+            }
+            let track = match optional_string(args, "track") {
+                Some(raw) => Some(liberal::normalize_track(&raw).ok_or_else(|| {
+                    format!("'track' must be one of {}", liberal::TRACKS.join("/"))
+                })?),
+                None => None,
+            };
+            let college = optional_string(args, "college").unwrap_or_default();
+            let department = optional_string(args, "department").unwrap_or_default();
+            let as_of = optional_date(args, "as_of")?;
+            Ok(load::validate_course_load(
+                &semesters,
+                level,
+                allowance.trim(),
+                track,
+                college.trim(),
+                department.trim(),
+                as_of.as_deref(),
+            ))
         }
         "ssu_get_calendar_events" => {
             let keyword = optional_string(args, "keyword").unwrap_or_default();
             let month = optional_i64(args, "month");
             let limit = optional_i64(args, "limit").unwrap_or(20).max(1) as usize;
+            let as_of = optional_date(args, "as_of")?;
 
-            let rows = parse_calendar_rows();
+            let rows = parse_calendar_rows(as_of.as_deref());
             let lowered_keyword = keyword.to_lowercase();
             let mut events = Vec::new();
 
@@ -766,19 +1471,57 @@ fn call_tool(
             Ok(json!({
                 "keyword": keyword,
                 "month": month,
+                "as_of": as_of,
+                "snapshot": resolve_snapshot(PATH_CALENDAR, as_of.as_deref())
+                    .map(|snapshot| snapshot.effective_from),
                 "count": events.len(),
                 "events": events,
             }))
         }
+        "ssu_calculate_tuition_refund" => {
+            let date = optional_date(args, "date")?
+                .and_then(|date| CivilDate::parse(&date))
+                .ok_or("'date' is required and must be a YYYY-MM-DD date string")?;
+            let tuition = required_count(args, "tuition")?;
+            let reason = optional_string(args, "reason").unwrap_or_else(|| "휴학".to_string());
+            if !refund::REFUND_REASONS.contains(&reason.trim()) {
+                return Err(format!("'reason' must be one of {}", refund::REFUND_REASONS.join("/")));
+            }
+            let as_of = optional_date(args, "as_of")?;
+            Ok(refund::calculate_tuition_refund(date, tuition, reason.trim(), as_of.as_deref()))
+        }
+        "ssu_analyze_retake" => {
+            let course = required_string(args, "course")?;
+            let semester = required_string(args, "semester")?;
+            let Some((year, term)) = load::parse_term(&semester) else {
+                return Err("'semester' must look like 2024-1, 2024-2, 2024-여름 or 2024-겨울".to_string());
+            };
+            let as_of = optional_date(args, "as_of")?;
+            Ok(retake::analyze_retake(course.trim(), semester.trim(), year, term, as_of.as_deref()))
+        }
+        "ssu_calculate_gpa" => {
+            let courses = gpa::parse_graded_courses(args)?;
+            let policy = gpa::retake_policy(args)?;
+            let as_of = optional_date(args, "as_of")?;
+            Ok(gpa::calculate(&courses, policy, as_of.as_deref()).value)
+        }
         "ssu_check_scholarship_threshold" => {
-            let gpa = optional_f64(args, "gpa");
-            let earned_credits = optional_i64(args, "earned_credits");
+            let as_of = optional_date(args, "as_of")?;
+            let report = gpa::report_from_args(args, as_of.as_deref())?;
+            let basis = gpa::gpa_basis(args)?;
+            let computed = report.as_ref().map(|report| report.on_basis(basis));
+            let gpa = optional_f64(args, "gpa").or(computed.as_ref().and_then(|(gpa, _, _)| *gpa));
+            let earned_credits =
+                optional_i64(args, "earned_credits").or(computed.as_ref().and_then(|(_, credits, _)| *credits));
             let min_gpa = optional_f64(args, "min_gpa");
             let min_credits = optional_i64(args, "min_credits");
 
             if min_gpa.is_none() && min_credits.is_none() {
+                if let Some(gap) = gaps::gap_named("장학금 선발 기준", as_of.as_deref()) {
+                    return Ok(gaps::gap_result(&gap, &[]));
+                }
                 return Ok(json!({
-                    "judgement": "판정 불가",
+                    "judgement": gaps::GAP_JUDGEMENT,
                     "reason": "현재 docs 묶음에는 장학금 정량 선발기준 문서가 없음",
                     "required_action": "장학 규정 PDF/URL 제공 필요",
                     "citation": "(학칙.pdf, p.14) + source-map known gap"
@@ -799,33 +1542,100 @@ fn call_tool(
                 .map(|min| earned_credits.map(|v| v >= min).unwrap_or(false))
                 .unwrap_or(true);
 
+            let mut notes = vec![
+                json!("이 결과는 사용자가 제공한 역치 기준값에 대한 비교임"),
+                json!("학교 공식 장학 세부기준 문서로 최종 확인 필요"),
+            ];
+            if let Some((_, _, label)) = &computed {
+                notes.push(json!(format!(
+                    "입력하지 않은 gpa/earned_credits는 courses의 {label} 평점·취득학점으로 계산 (gpa_basis로 직전학기/누계 선택)"
+                )));
+            }
+
             Ok(json!({
                 "judgement": if meets_gpa && meets_credits { "가능" } else { "불가" },
                 "input": {
-                    "gpa": gpa,
+                    "gpa": gpa.map(gpa::round2),
+                    "gpa_raw": gpa,
                     "earned_credits": earned_credits,
                     "min_gpa": min_gpa,
                     "min_credits": min_credits,
                 },
                 "gap": gap,
-                "notes": [
-                    "이 결과는 사용자가 제공한 역치 기준값에 대한 비교임",
-                    "학교 공식 장학 세부기준 문서로 최종 확인 필요"
-                ]
+                "gpa_calculation": report.map(|report| report.value),
+                "notes": notes
             }))
         }
-        "ssu_list_sources" => Ok(json!({
-            "docs": [
-                "학칙.pdf",
-                "학점 이수 체계.pdf",
-                "교양 필수.pdf",
-                "교양 선택.pdf",
-                "학사 일정.pdf"
-            ],
-            "normalized_md": NORMALIZED_FILES,
-            "raw_md": RAW_FILES,
-            "references": REFERENCE_FILES,
-        })),
+        "ssu_list_programs" => {
+            let college = optional_string(args, "college");
+            let as_of = optional_date(args, "as_of")?;
+            Ok(programs::list_programs(college.as_deref(), as_of.as_deref()))
+        }
+        "ssu_list_sources" => {
+            let as_of = optional_date(args, "as_of")?;
+            Ok(json!({
+                "as_of": as_of,
+                "docs": sources::source_pdfs(as_of.as_deref()),
+                "normalized_md": NORMALIZED_FILES,
+                "raw_md": RAW_FILES,
+                "references": REFERENCE_FILES,
+                "snapshots": SNAPSHOTS
+                    .iter()
+                    .map(|snapshot| json!({
+                        "file": snapshot.path,
+                        "effective_from": snapshot.effective_from,
+                    }))
+                    .collect::<Vec<_>>(),
+                "documents": sources::list_documents(as_of.as_deref()),
+                "known_gaps": gaps::registry(as_of.as_deref())
+                    .iter()
+                    .map(gaps::gap_summary)
+                    .collect::<Vec<_>>(),
+            }))
+        }
+        "ssu_query_table" => {
+            let file = optional_string(args, "file");
+            let section = optional_string(args, "table");
+            let filters = args
+                .get("filters")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default();
+            let exact = optional_string(args, "match").as_deref() == Some("exact");
+            let limit = optional_i64(args, "limit").unwrap_or(50).max(1) as usize;
+            let as_of = optional_date(args, "as_of")?;
+            Ok(table::query_tables(
+                file.as_deref(),
+                section.as_deref(),
+                &filters,
+                exact,
+                limit,
+                as_of.as_deref(),
+            ))
+        }
+        "ssu_diff_sources" => {
+            let file = required_string(args, "file")?;
+            let from = optional_date(args, "from")?.ok_or("'from' is required and must be a YYYY-MM-DD date string")?;
+            let to = optional_date(args, "to")?.or(optional_date(args, "as_of")?);
+            let (Some(base), Some(target)) = (
+                resolve_snapshot(&file, Some(&from)),
+                resolve_snapshot(&file, to.as_deref()),
+            ) else {
+                return Ok(json!({
+                    "judgement": "판정 불가",
+                    "reason": format!("{file}: SNAPSHOTS에 등록된 스냅샷이 없음"),
+                }));
+            };
+            let mut result = diff::diff_snapshots(base, target);
+            let from_date = CivilDate::parse(&from).ok_or("'from' must be a YYYY-MM-DD date string")?;
+            if base.effective_date() > from_date {
+                result["from_warning"] = snapshot_warning(
+                    &from,
+                    vec![json!({ "file": base.path, "effective_from": base.effective_from })],
+                );
+            }
+            Ok(result)
+        }
         _ => Err(format!("Unknown tool: {name}")),
     }
 }
@@ -837,7 +1647,10 @@ fn tool_definitions() -> Vec<Value> {
             "description": "학사 질의를 5개 워크플로우 카테고리로 분류한다.",
             "inputSchema": {
                 "type": "object",
-                "properties": { "question": { "type": "string" } },
+                "properties": {
+                    "question": { "type": "string" },
+                    "as_of": { "type": "string" }
+                },
                 "required": ["question"],
                 "additionalProperties": false
             }
@@ -850,7 +1663,8 @@ fn tool_definitions() -> Vec<Value> {
                 "properties": {
                     "question": { "type": "string" },
                     "category": { "type": "string" },
-                    "max_hits": { "type": "integer" }
+                    "max_hits": { "type": "integer" },
+                    "as_of": { "type": "string" }
                 },
                 "required": ["question"],
                 "additionalProperties": false
@@ -863,7 +1677,8 @@ fn tool_definitions() -> Vec<Value> {
                 "type": "object",
                 "properties": {
                     "question": { "type": "string" },
-                    "max_hits": { "type": "integer" }
+                    "max_hits": { "type": "integer" },
+                    "as_of": { "type": "string" }
                 },
                 "required": ["question"],
                 "additionalProperties": false
@@ -871,18 +1686,91 @@ fn tool_definitions() -> Vec<Value> {
         }),
         json!({
             "name": "ssu_evaluate_graduation",
-            "description": "학점 이수 체계 기준으로 졸업요건 충족 여부(초안)를 계산한다.",
+            "description": "학점 이수 체계 기준으로 졸업요건 충족 여부(초안)를 계산한다. accreditation=true면 공학교육인증 영역별 부족분을 졸업학점 gap과 따로 보고한다. 졸업논문/종합시험, 채플, TOPIK 등 학점 외 요건은 requirements 체크리스트로 met/unmet/unknown을 표시하며, unknown이 있으면 가능 대신 판정 불가를 반환한다.",
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "college": { "type": "string" },
-                    "department": { "type": "string" },
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
                     "major_type": { "type": "string" },
                     "earned_liberal_required": { "type": "integer" },
                     "earned_liberal_elective": { "type": "integer" },
                     "earned_major_basic": { "type": "integer" },
                     "earned_major": { "type": "integer" },
-                    "earned_total": { "type": "integer" }
+                    "earned_total": { "type": "integer" },
+                    "admission_year": { "type": "integer" },
+                    "accreditation": { "type": "boolean", "description": "공학교육인증(제5조의2) 요건도 함께 확인" },
+                    "accreditation_credits": {
+                        "type": "object",
+                        "description": "인증 영역별 이수학점",
+                        "properties": {
+                            "msc": { "type": "integer", "description": "MSC" },
+                            "liberal": { "type": "integer", "description": "전문교양" },
+                            "engineering": { "type": "integer", "description": "공학주제" },
+                            "design": { "type": "integer", "description": "설계" }
+                        },
+                        "additionalProperties": false
+                    },
+                    "accreditation_required": {
+                        "type": "object",
+                        "description": "학과 인증 이수체계표의 영역별 기준(코퍼스에 없어 알고 있을 때만 입력)",
+                        "properties": {
+                            "msc": { "type": "integer" },
+                            "liberal": { "type": "integer" },
+                            "engineering": { "type": "integer" },
+                            "design": { "type": "integer" }
+                        },
+                        "additionalProperties": false
+                    },
+                    "accreditation_required_courses_completed": { "type": "boolean", "description": "인증필수 교과목 이수 여부" },
+                    "thesis_or_exam_passed": { "type": "boolean", "description": "졸업논문 또는 졸업종합시험 합격 여부" },
+                    "thesis_substitute": {
+                        "type": "string",
+                        "description": "졸업논문/종합시험 대체 수단(졸업작품, 외국어능력시험, 자격심사 등)으로 충족했을 때 그 수단"
+                    },
+                    "chapel_completed": { "type": "boolean", "description": "기독교 교육(채플) 이수 완료 여부" },
+                    "international_student": { "type": "boolean", "description": "외국인학생 여부" },
+                    "topik_level": { "type": "integer", "description": "취득한 TOPIK 급수" },
+                    "convergence_major_completed": { "type": "boolean", "description": "융합특성화자유전공학부: 미래사회수요융합전공 이수 완료 여부" },
+                    "as_of": { "type": "string" }
+                },
+                "required": [
+                    "college",
+                    "major_type",
+                    "earned_liberal_required",
+                    "earned_liberal_elective",
+                    "earned_major_basic",
+                    "earned_major",
+                    "earned_total"
+                ],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_plan_graduation",
+            "description": "졸업요건 부족분을 남은 정규등록학기(제32조 5항)와 연간 38학점 한도 안에서 학기별·이수구분별 학점 계획으로 나누고, 교양필수 미이수 과목을 수강 학기에 배치한다. 정규학기 안 졸업이 불가능하면 부족 학점을 보고한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "major_type": {
+                        "type": "string",
+                        "enum": ["단일전공자", "부전공자", "복수전공자(주전공)", "복수전공자(복수전공)"]
+                    },
+                    "earned_liberal_required": { "type": "integer" },
+                    "earned_liberal_elective": { "type": "integer" },
+                    "earned_major_basic": { "type": "integer" },
+                    "earned_major": { "type": "integer" },
+                    "earned_total": { "type": "integer" },
+                    "completed_semesters": { "type": "integer", "description": "지금까지 등록한 정규학기 수" },
+                    "next_semester": { "type": "string", "description": "다음 정규학기(예: 2026-2)" },
+                    "credits_this_year": { "type": "integer", "description": "다음 학기가 2학기일 때 같은 학년도 1학기에 이수한 학점" },
+                    "semester_cap": { "type": "integer", "description": "학기당 상한(시행세칙 기준을 알고 있을 때)" },
+                    "track": { "type": "string", "enum": ["인문사회", "이공", "융특"] },
+                    "completed_courses": { "type": "array", "items": { "type": "string" }, "description": "이수한 교양필수 과목명" },
+                    "admission_year": { "type": "integer" },
+                    "as_of": { "type": "string" }
                 },
                 "required": [
                     "college",
@@ -891,11 +1779,398 @@ fn tool_definitions() -> Vec<Value> {
                     "earned_liberal_elective",
                     "earned_major_basic",
                     "earned_major",
+                    "earned_total",
+                    "completed_semesters"
+                ],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_evaluate_early_graduation",
+            "description": "조기졸업(제46조 4항) 조건을 평균평점, 제6~7학기 등록, 졸업학점·이수구분별 학점으로 나눠 각각 근거와 함께 판정하고, 연간 이수학점 한도로 가장 이른 졸업 가능 학기를 계산한다. gpa 대신 성적이 있는 courses를 주면 누계 평점을 계산해 쓴다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "major_type": {
+                        "type": "string",
+                        "enum": ["단일전공자", "부전공자", "복수전공자(주전공)", "복수전공자(복수전공)"]
+                    },
+                    "gpa": { "type": "number", "description": "현재 평균평점(0~4.5). 생략 시 courses로 계산" },
+                    "courses": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "credits": { "type": "integer" },
+                                "grade": { "type": "string", "description": "A+~D-, F, P, NP. 생략 시 수강중" },
+                                "semester": { "type": "string", "description": "예: 2023-1" }
+                            },
+                            "required": ["name", "credits"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "retake_policy": { "type": "string", "enum": ["대체", "모두 반영"], "description": "재수강 성적 처리(기본 대체)" },
+                    "registered_semesters": { "type": "integer", "description": "지금까지 등록한 정규학기 수" },
+                    "earned_total": { "type": "integer" },
+                    "earned_liberal_required": { "type": "integer" },
+                    "earned_liberal_elective": { "type": "integer" },
+                    "earned_major_basic": { "type": "integer" },
+                    "earned_major": { "type": "integer" },
+                    "semester_cap": { "type": "integer", "description": "학기당 상한(시행세칙 기준을 알고 있을 때)" },
+                    "admission_year": { "type": "integer" },
+                    "thesis_or_exam_passed": { "type": "boolean", "description": "졸업논문 또는 졸업종합시험 합격 여부" },
+                    "thesis_substitute": {
+                        "type": "string",
+                        "description": "졸업논문/종합시험 대체 수단(졸업작품, 외국어능력시험, 자격심사 등)으로 충족했을 때 그 수단"
+                    },
+                    "chapel_completed": { "type": "boolean", "description": "기독교 교육(채플) 이수 완료 여부" },
+                    "international_student": { "type": "boolean", "description": "외국인학생 여부" },
+                    "topik_level": { "type": "integer", "description": "취득한 TOPIK 급수" },
+                    "convergence_major_completed": { "type": "boolean", "description": "융합특성화자유전공학부: 미래사회수요융합전공 이수 완료 여부" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["college", "major_type", "registered_semesters", "earned_total"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_compare_graduation_scenarios",
+            "description": "같은 이수학점으로 현재 학과(부)의 단일전공/부전공/복수전공 열과 전과 후보 학과(부)를 나란히 평가해 시나리오별 부족 학점과 불허 경로를 표로 보여 준다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string", "description": "현재 대학명 또는 약칭" },
+                    "department": { "type": "string", "description": "현재 학과(부)/전공명 또는 약칭" },
+                    "earned_liberal_required": { "type": "integer" },
+                    "earned_liberal_elective": { "type": "integer" },
+                    "earned_major_basic": { "type": "integer" },
+                    "earned_major": { "type": "integer" },
+                    "earned_total": { "type": "integer" },
+                    "major_types": {
+                        "type": "array",
+                        "items": {
+                            "type": "string",
+                            "enum": ["단일전공자", "부전공자", "복수전공자(주전공)", "복수전공자(복수전공)"]
+                        },
+                        "description": "비교할 열(기본: 전체)"
+                    },
+                    "alternatives": {
+                        "type": "array",
+                        "description": "전과 후보 학과(부)",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "college": { "type": "string" },
+                                "department": { "type": "string" }
+                            },
+                            "required": ["college"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "admission_year": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "required": [
+                    "college",
+                    "earned_liberal_required",
+                    "earned_liberal_elective",
+                    "earned_major_basic",
+                    "earned_major",
+                    "earned_total"
+                ],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_evaluate_multi_major",
+            "description": "주전공과 복수전공/부전공을 함께 받아 불허 여부와 원문 규정 메모를 확인하고 전공별 부족 학점을 합산 보고한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "secondary_programs": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "college": { "type": "string" },
+                                "department": { "type": "string" },
+                                "program_type": { "type": "string", "description": "복수전공 또는 부전공" },
+                                "earned_credits": { "type": "integer" }
+                            },
+                            "required": ["college", "program_type", "earned_credits"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "earned_liberal_required": { "type": "integer" },
+                    "earned_liberal_elective": { "type": "integer" },
+                    "earned_major_basic": { "type": "integer" },
+                    "earned_major": { "type": "integer", "description": "주전공 이수학점" },
+                    "earned_total": { "type": "integer" },
+                    "admission_year": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "required": [
+                    "college",
+                    "secondary_programs",
+                    "earned_liberal_required",
+                    "earned_liberal_elective",
+                    "earned_major_basic",
+                    "earned_major",
                     "earned_total"
                 ],
                 "additionalProperties": false
             }
         }),
+        json!({
+            "name": "ssu_audit_transcript",
+            "description": "성적표 과목 목록을 이수구분별로 합산하고 F/재수강/중복을 검출한 뒤 학점 이수 체계 기준으로 졸업요건을 비교한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "major_type": { "type": "string" },
+                    "courses": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "credits": { "type": "integer" },
+                                "category": { "type": "string", "description": "이수구분(교필/교선/전기/전필/전선/복필/복선/부필/부선/일선)" },
+                                "grade": { "type": "string", "description": "A+~D-, F, P, NP. 생략 시 수강중" },
+                                "semester": { "type": "string", "description": "예: 2023-1" },
+                                "balance_area": { "type": "string" },
+                                "offering_college": { "type": "string" }
+                            },
+                            "required": ["name", "credits", "category"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "admission_year": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["college", "major_type", "courses"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_check_liberal_required",
+            "description": "이수한 과목명과 계열/입학년도로 교양필수 미이수 과목과 과목별 수강 예정 학기를 확인한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "completed_courses": { "type": "array", "items": { "type": "string" } },
+                    "track": { "type": "string", "description": "계열: 인문사회/이공/융특" },
+                    "admission_year": { "type": "integer" },
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["completed_courses", "track", "admission_year"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_check_liberal_elective",
+            "description": "입학년도별 교양선택 이수체계(BALANCE 영역, 교과구분별 최소 이수요건)로 영역 충족 여부를 확인한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "courses": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "area": { "type": "string", "description": "영역명(예: 인간·언어, 3영역, 인성과 리더십)" },
+                                "credits": { "type": "integer" },
+                                "offering_college": { "type": "string", "description": "개설 단과대학(2020학년도 입학자 제외 규정 판별용)" }
+                            },
+                            "required": ["name", "area"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "admission_year": { "type": "integer" },
+                    "college": { "type": "string" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["courses", "admission_year"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_evaluate_standing",
+            "description": "제43조 학년 인정 기준으로 누계 이수학점과 등록 학기 수에 따른 이수 인정 학년과 다음 학년까지 부족분을 계산한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "cumulative_credits": { "type": "integer" },
+                    "registered_semesters": { "type": "integer", "description": "휴학을 제외한 등록 학기 수" },
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(5년제 건축학전공 판별용)" },
+                    "admission_year": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["cumulative_credits", "registered_semesters"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_evaluate_academic_standing",
+            "description": "학기별 평균평점으로 제54조 학사경고 학기와 현재 연속 경고 횟수, 제29조 1항 5호 연속 학사경고 제적 위험, 다음 학기 경고를 피하는 최소 평점을 계산한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "semesters": {
+                        "type": "array",
+                        "description": "오래된 학기부터 순서대로",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "semester": { "type": "string", "description": "예: 2023-1" },
+                                "gpa": { "type": ["number", "null"], "description": "학기 평균평점. 휴학 등 평점이 없으면 null" },
+                                "attended": { "type": "boolean", "description": "등록금 납부 후 수강하지 않았으면 false" }
+                            },
+                            "required": ["semester"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "courses": {
+                        "type": "array",
+                        "description": "semesters 대신 과목 성적으로 학기 평점을 계산",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "credits": { "type": "integer" },
+                                "grade": { "type": "string", "description": "A+~D-, F, P, NP. 생략 시 수강중" },
+                                "semester": { "type": "string", "description": "예: 2023-1" }
+                            },
+                            "required": ["name", "credits"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "level": { "type": "string", "enum": ["학사", "대학원"] },
+                    "as_of": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_evaluate_leave",
+            "description": "제27조 휴학 기준으로 유형별 휴학 이력과 입학 구분에서 남은 통산 휴학 학기, 새 휴학 신청 가능 여부(입학 직후 제한, 연속 휴학, 창업휴학 한도, 통산 한도)와 적용된 예외를 계산한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "admission_category": {
+                        "type": "string",
+                        "enum": ["신입학", "편입학", "석사과정", "박사과정", "석박통합과정"]
+                    },
+                    "admission_year": { "type": "integer", "description": "2022학년도 이전 신입학자 통산 10학기 판별용" },
+                    "registered_semesters": { "type": "integer", "description": "입학 후 등록한 학기 수(입학 직후 2학기 제한 판별용)" },
+                    "consecutive_leave_semesters": { "type": "integer", "description": "신청 학기 직전까지 계속된 휴학 학기 수" },
+                    "leaves": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "type": { "type": "string", "enum": ["일반", "질병", "군입대", "임신·출산·육아", "창업", "장기근무"] },
+                                "semesters": { "type": "integer" }
+                            },
+                            "additionalProperties": false
+                        }
+                    },
+                    "request": {
+                        "type": "object",
+                        "description": "새로 신청할 휴학(생략 시 일반휴학 1학기)",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["일반", "질병", "군입대", "임신·출산·육아", "창업", "장기근무"] },
+                            "semesters": { "type": "integer" }
+                        },
+                        "additionalProperties": false
+                    },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["admission_category"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_track_enrollment",
+            "description": "학기별 등록/휴학/계절학기 이력으로 등록한 정규학기 수와 제32조 5항 정규등록학기(학사 8학기, 건축학전공 10학기) 대비 남은·초과 학기, 제16조 수업연한과 제17조 재학연한(삭제) 상태, 초과 시 등록 관련 안내를 반환한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "terms": {
+                        "type": "array",
+                        "description": "오래된 학기부터 순서대로",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "semester": { "type": "string", "description": "예: 2023-1, 2023-여름" },
+                                "status": { "type": "string", "enum": ["등록", "휴학", "계절학기"] }
+                            },
+                            "required": ["semester"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "program": { "type": "string", "enum": ["학사", "석사과정", "박사과정", "석박통합과정"] },
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명(5년제 건축학전공 판별용)" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["terms"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_validate_course_load",
+            "description": "학기별 수강 계획을 제43조 연간 38학점, 교양필수 계열별 학기 편성학점, 제44조 대학원 학기당 한도(9/12/15학점)와 비교해 초과 신청을 표시한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "semesters": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "semester": { "type": "string", "description": "예: 2026-1, 2026-2, 2026-여름" },
+                                "year_level": { "type": "integer", "description": "해당 학기의 학년(교양필수 편성 비교용)" },
+                                "courses": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "object",
+                                        "properties": {
+                                            "name": { "type": "string" },
+                                            "credits": { "type": "integer" },
+                                            "category": { "type": "string", "description": "이수구분(교필 등). 대학원 선수과목은 '선수'" }
+                                        },
+                                        "required": ["name", "credits"],
+                                        "additionalProperties": false
+                                    }
+                                }
+                            },
+                            "required": ["semester", "courses"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "level": { "type": "string", "enum": ["학사", "대학원"] },
+                    "allowance": { "type": "string", "enum": ["기본", "예외", "연수과정"], "description": "대학원 학기당 한도: 기본 9, 예외 12(제44조 2항), 연수과정 15(제44조 4항)" },
+                    "track": { "type": "string", "enum": ["인문사회", "이공", "융특"] },
+                    "college": { "type": "string", "description": "대학명 또는 약칭(IT대, 경통 등)" },
+                    "department": { "type": "string", "description": "학과(부)/전공명 또는 약칭(소웨, 글미 등)" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["semesters"],
+                "additionalProperties": false
+            }
+        }),
         json!({
             "name": "ssu_get_calendar_events",
             "description": "학사 일정에서 일정 항목을 조회한다.",
@@ -904,31 +2179,153 @@ fn tool_definitions() -> Vec<Value> {
                 "properties": {
                     "keyword": { "type": "string" },
                     "month": { "type": "integer" },
-                    "limit": { "type": "integer" }
+                    "limit": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_calculate_tuition_refund",
+            "description": "휴학(또는 자퇴) 날짜와 수업료로 학사 일정의 휴학 시 수업료 반환 기간(전액 반환, 1/6·1/3·1/2 차감)을 찾아 반환 금액과 기간 날짜, 학사 일정 인용을 반환한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "date": { "type": "string", "description": "휴학/자퇴 신청일(YYYY-MM-DD)" },
+                    "tuition": { "type": "integer", "description": "납부한 수업료(원)" },
+                    "reason": { "type": "string", "enum": ["휴학", "자퇴"] },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["date", "tuition"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_analyze_retake",
+            "description": "2022학년도 이전 교양필수 교과목의 재수강 분반 운영 목록으로 대상 학기에 재수강 분반이 있는지 판정하고, 없으면 대체 과목 정보와 코퍼스에 없는 기준(대체과목, 횟수 한도)을 인용과 함께 반환한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "course": { "type": "string", "description": "예: 독서와토론" },
+                    "semester": { "type": "string", "description": "재수강할 학기. 예: 2024-1" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["course", "semester"],
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_calculate_gpa",
+            "description": "별표 4 등급별 평점으로 학기별·누계 평균평점을 계산한다. P/NP는 제외하고 재수강은 대체(마지막 성적) 또는 모두 반영 방식으로 처리한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "courses": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "credits": { "type": "integer" },
+                                "grade": { "type": "string", "description": "A+~D-, F, P, NP. 생략 시 수강중" },
+                                "semester": { "type": "string", "description": "예: 2023-1" }
+                            },
+                            "required": ["name", "credits"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "retake_policy": { "type": "string", "enum": ["대체", "모두 반영"], "description": "재수강 성적 처리(기본 대체)" },
+                    "as_of": { "type": "string" }
                 },
+                "required": ["courses"],
                 "additionalProperties": false
             }
         }),
         json!({
             "name": "ssu_check_scholarship_threshold",
-            "description": "장학 역치 비교. 기준값 미제공 시 '판정 불가'를 반환한다.",
+            "description": "장학 역치 비교. 기준값 미제공 시 '판정 불가'를 반환한다. gpa 대신 courses를 주면 직전 학기(또는 누계) 평점을 계산해 쓴다.",
             "inputSchema": {
                 "type": "object",
                 "properties": {
                     "gpa": { "type": "number" },
                     "earned_credits": { "type": "integer" },
+                    "courses": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "name": { "type": "string" },
+                                "credits": { "type": "integer" },
+                                "grade": { "type": "string", "description": "A+~D-, F, P, NP. 생략 시 수강중" },
+                                "semester": { "type": "string", "description": "예: 2023-1" }
+                            },
+                            "required": ["name", "credits"],
+                            "additionalProperties": false
+                        }
+                    },
+                    "retake_policy": { "type": "string", "enum": ["대체", "모두 반영"], "description": "재수강 성적 처리(기본 대체)" },
+                    "gpa_basis": { "type": "string", "enum": ["직전학기", "누계"], "description": "courses로 계산할 때 기준(기본 직전학기)" },
                     "min_gpa": { "type": "number" },
-                    "min_credits": { "type": "integer" }
+                    "min_credits": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_list_programs",
+            "description": "학점 이수 체계 표에 있는 대학과 학과(부) 행(전체/외 포함)과 각 행이 적용되는 카탈로그 학과(부)/전공을 나열한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "college": { "type": "string" },
+                    "as_of": { "type": "string" }
                 },
                 "additionalProperties": false
             }
         }),
         json!({
             "name": "ssu_list_sources",
-            "description": "서버가 참조하는 주요 소스 파일 경로를 반환한다.",
+            "description": "소스 파일 경로와 문서별 메타데이터(검증일, 페이지 수, 용도, 원문 링크, known gap)를 반환한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "as_of": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_query_table",
+            "description": "코퍼스의 마크다운 표를 조회한다. table/filters 없이 호출하면 표 목록을, 있으면 컬럼 값으로 필터링한 행을 반환한다.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "file": { "type": "string" },
+                    "table": { "type": "string" },
+                    "filters": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" }
+                    },
+                    "match": { "type": "string", "enum": ["contains", "exact"] },
+                    "limit": { "type": "integer" },
+                    "as_of": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        }),
+        json!({
+            "name": "ssu_diff_sources",
+            "description": "두 시점의 문서 스냅샷을 조문/표 행 단위로 비교한다. to 생략 시 as_of 시점(없으면 최신본)과 비교한다.",
             "inputSchema": {
                 "type": "object",
-                "properties": {},
+                "properties": {
+                    "file": { "type": "string" },
+                    "from": { "type": "string" },
+                    "to": { "type": "string" },
+                    "as_of": { "type": "string" }
+                },
+                "required": ["file", "from"],
                 "additionalProperties": false
             }
         }),
@@ -1025,11 +2422,7 @@ fn process_single_rpc(request: &Value) -> Option<Value> {
         _ => Err(rpc_failure(-32601, format!("Method not found: {method}"))),
     };
 
-    if id.is_none() {
-        return None;
-    }
-
-    let id = id.unwrap_or(Value::Null);
+    let id = id?;
     Some(match result {
         Ok(result) => rpc_success(id, result),
         Err(err) => rpc_error(id, err.code, &err.message),
@@ -1092,3 +2485,221 @@ pub async fn fetch(req: Request, _env: Env, _ctx: Context) -> Result<Response> {
         _ => Response::error("Not Found", 404),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn every_snapshot_has_a_valid_effective_date() {
+        for snapshot in SNAPSHOTS {
+            assert!(CivilDate::parse_iso(snapshot.effective_from).is_some(), "{}", snapshot.path);
+        }
+    }
+
+    #[test]
+    fn as_of_before_a_snapshot_falls_back_with_a_warning() {
+        let result = call_tool(
+            "ssu_get_calendar_events",
+            &args(json!({ "keyword": "수강신청", "as_of": "2025-09-01" })),
+        )
+        .expect("tool call");
+        assert!(result.get("judgement").is_none_or(|judgement| judgement != "판정 불가"));
+        assert_eq!(
+            result["snapshot_warning"]["fallback_snapshots"],
+            json!([{ "file": PATH_CALENDAR, "effective_from": "2026-01-01" }])
+        );
+        let hakchik = resolve_snapshot(PATH_HAKCHIK, Some("2025-09-01")).expect("학칙 snapshot");
+        assert_eq!(hakchik.effective_from, "2024-03-01");
+    }
+
+    #[test]
+    fn snapshot_dates_compare_as_dates() {
+        let calendar = resolve_snapshot(PATH_CALENDAR, Some("12025-01-01")).expect("calendar snapshot");
+        assert_eq!(calendar.effective_from, "2026-01-01");
+        assert!(fallback_snapshots("12025-01-01").is_empty());
+        assert_eq!(fallback_snapshots("2023-02-28").len(), SNAPSHOTS.len());
+    }
+
+    #[test]
+    fn diff_from_before_first_snapshot_is_flagged() {
+        let result = call_tool(
+            "ssu_diff_sources",
+            &args(json!({ "file": PATH_CREDIT_SYSTEM, "from": "2023-03-01" })),
+        )
+        .expect("tool call");
+        assert_eq!(result["identical"], true);
+        assert_eq!(result["from_warning"]["fallback_snapshots"][0]["effective_from"], "2024-03-01");
+    }
+
+    fn credit_row(college: &str, department: &str) -> CreditRow {
+        let mut values = Map::new();
+        values.insert("대학".to_string(), json!(college));
+        values.insert("학과/학부".to_string(), json!(department));
+        CreditRow { values }
+    }
+
+    #[test]
+    fn department_rows_match_exactly() {
+        let rows = load_credit_rows(None);
+        let program = catalog::resolve("공대", "건축학부 건축학전공", None);
+        let matched = match_credit_row(&rows, &program).expect("건축학 row");
+        assert_eq!(row_string(&matched.row, "학과/학부"), "건축학부 건축학전공");
+        assert_eq!(matched.confidence, "high");
+        assert_eq!(matched.reason, "대학 exact, 학과 exact");
+        assert_eq!(matched.candidates.len(), 1);
+
+        let except = credit_row("공과대학", "건축학부 건축학전공 외");
+        assert!(score_credit_row(&except, &program).is_none());
+    }
+
+    #[test]
+    fn departments_missing_from_a_listed_row_fall_into_the_except_row() {
+        let rows = load_credit_rows(None);
+        let program = catalog::resolve("IT대학", "글로벌미디어학부", None);
+        let matched = match_credit_row(&rows, &program).expect("IT대학 row");
+        assert_eq!(row_string(&matched.row, "학과/학부"), "컴퓨터학부·소프트웨어학부 외");
+        assert_eq!(matched.reason, "대학 exact, 학과 외 (카탈로그)");
+        assert_eq!(matched.confidence, "high");
+
+        let listed = credit_row("IT대학", "컴퓨터학부·소프트웨어학부");
+        assert_eq!(score_credit_row(&listed, &program), Some((20, "exact", "불일치")));
+    }
+
+    #[test]
+    fn rows_within_the_margin_lower_confidence() {
+        let program = catalog::resolve("IT대학", "컴퓨터학부", None);
+        let rows = [
+            credit_row("IT대학", "전체"),
+            credit_row("IT대학", "소프트웨어학부 외"),
+            credit_row("IT대학", "컴퓨터학부"),
+        ];
+        assert_eq!(score_credit_row(&rows[2], &program), Some((40, "exact", "exact")));
+        assert_eq!(score_credit_row(&rows[1], &program), Some((38, "exact", "외 (카탈로그)")));
+
+        let matched = match_credit_row(&rows, &program).expect("match");
+        assert_eq!(row_string(&matched.row, "학과/학부"), "컴퓨터학부");
+        assert_eq!(matched.confidence, "low");
+        assert_eq!(matched.candidates.len(), 2);
+
+        let matched = match_credit_row(&[rows[0].clone(), rows[2].clone()], &program).expect("match");
+        assert_eq!(matched.confidence, "high");
+        assert_eq!(matched.candidates.len(), 1);
+    }
+
+    #[test]
+    fn negative_earned_credits_are_rejected() {
+        let base = json!({
+            "college": "IT대학",
+            "department": "컴퓨터학부",
+            "major_type": "단일전공자",
+            "earned_liberal_required": 19,
+            "earned_liberal_elective": 9,
+            "earned_major_basic": 15,
+            "earned_major": -3,
+            "earned_total": 133,
+            "completed_semesters": 8,
+            "secondary_programs": [],
+            "alternatives": [],
+        });
+        for tool in [
+            "ssu_evaluate_graduation",
+            "ssu_plan_graduation",
+            "ssu_evaluate_multi_major",
+            "ssu_compare_graduation_scenarios",
+        ] {
+            let error = call_tool(tool, &args(base.clone())).expect_err(tool);
+            assert_eq!(error, "'earned_major' must be zero or positive", "{tool}");
+        }
+
+        let secondary = json!({
+            "college": "IT대학",
+            "department": "컴퓨터학부",
+            "earned_liberal_required": 19,
+            "earned_liberal_elective": 9,
+            "earned_major_basic": 15,
+            "earned_major": 36,
+            "earned_total": 133,
+            "secondary_programs": [{ "college": "경영대학", "program_type": "부전공", "earned_credits": -1 }],
+        });
+        let error = call_tool("ssu_evaluate_multi_major", &args(secondary)).expect_err("negative secondary");
+        assert_eq!(error, "secondary_programs[0]: 'earned_credits' must be zero or positive");
+    }
+
+    #[test]
+    fn plan_rejects_seasonal_or_unreadable_next_semester() {
+        let mut request = json!({
+            "college": "IT대학",
+            "department": "컴퓨터학부",
+            "major_type": "단일전공자",
+            "earned_liberal_required": 19,
+            "earned_liberal_elective": 9,
+            "earned_major_basic": 15,
+            "earned_major": 30,
+            "earned_total": 73,
+            "completed_semesters": 4,
+            "next_semester": "2026-1",
+        });
+        assert!(call_tool("ssu_plan_graduation", &args(request.clone())).is_ok());
+        for label in ["2026-여름", "다음 학기"] {
+            request["next_semester"] = json!(label);
+            let error = call_tool("ssu_plan_graduation", &args(request.clone())).expect_err(label);
+            assert!(error.starts_with("'next_semester' must be a regular term"), "{error}");
+        }
+    }
+
+    #[test]
+    fn early_graduation_rejects_out_of_scale_gpa_and_negative_categories() {
+        let mut request = json!({
+            "college": "IT대학",
+            "department": "컴퓨터학부",
+            "major_type": "단일전공자",
+            "gpa": 4.2,
+            "earned_total": 133,
+            "registered_semesters": 6,
+        });
+        assert!(call_tool("ssu_evaluate_early_graduation", &args(request.clone())).is_ok());
+        request["gpa"] = json!(4.5);
+        assert!(call_tool("ssu_evaluate_early_graduation", &args(request.clone())).is_ok());
+
+        for gpa in [4.51, -0.1] {
+            request["gpa"] = json!(gpa);
+            let error = call_tool("ssu_evaluate_early_graduation", &args(request.clone())).expect_err("gpa");
+            assert_eq!(error, "'gpa' must be between 0 and 4.5 (학칙.pdf, p.57, 별표 4)");
+        }
+
+        request["gpa"] = json!(4.2);
+        request["earned_liberal_required"] = json!(19);
+        request["earned_liberal_elective"] = json!(9);
+        request["earned_major_basic"] = json!(-15);
+        request["earned_major"] = json!(72);
+        let error = call_tool("ssu_evaluate_early_graduation", &args(request)).expect_err("category");
+        assert_eq!(error, "'earned_major_basic' must be zero or positive");
+    }
+
+    #[test]
+    fn tuition_refund_rejects_bad_dates_amounts_and_reasons() {
+        let base = json!({ "date": "2026-04-01", "tuition": 3_000_000, "reason": "자퇴" });
+        assert!(call_tool("ssu_calculate_tuition_refund", &args(base.clone())).is_ok());
+        let cases = [
+            ("date", Value::Null, "'date' is required and must be a YYYY-MM-DD date string"),
+            ("date", json!("2026-02-30"), "'date' must be a YYYY-MM-DD date string"),
+            ("tuition", json!(-1), "'tuition' must be zero or positive"),
+            ("reason", json!("제적"), "'reason' must be one of 휴학/자퇴"),
+        ];
+        for (key, value, expected) in cases {
+            let mut request = base.clone();
+            if value.is_null() {
+                request.as_object_mut().expect("object").remove(key);
+            } else {
+                request[key] = value;
+            }
+            let error = call_tool("ssu_calculate_tuition_refund", &args(request)).expect_err(key);
+            assert_eq!(error, expected, "{key}");
+        }
+    }
+}
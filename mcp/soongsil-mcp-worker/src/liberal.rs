@@ -0,0 +1,302 @@
+use serde_json::{json, Value};
+
+use crate::catalog::{self, Program};
+use crate::elective;
+use crate::table::{self, CorpusTable};
+use crate::transcript::course_key;
+use crate::{extract_first_int, PATH_LIBERAL_REQUIRED};
+
+pub(crate) const TRACKS: &[&str] = &["인문사회", "이공", "융특"];
+
+pub(crate) fn normalize_track(raw: &str) -> Option<&'static str> {
+    let compact: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+    match compact.as_str() {
+        "인문사회" | "인문·사회" | "인문사회계열" => Some("인문사회"),
+        "이공" | "이공계" | "이공계열" => Some("이공"),
+        "융특" | "융합특성화" | "융합특성화자유전공학부" => Some("융특"),
+        _ => None,
+    }
+}
+
+fn department_matches(token: &str, program: &Program, as_of: Option<&str>) -> bool {
+    let named = catalog::resolve("", token, as_of);
+    if named.known {
+        named.department == program.department
+    } else {
+        !program.known && program.department.contains(token)
+    }
+}
+
+fn audience_matches(cell: &str, track: &str, program: &Program, as_of: Option<&str>) -> bool {
+    let (college, department) = (program.college.as_str(), program.department.as_str());
+    let tokens: Vec<&str> = cell.split('·').map(str::trim).filter(|t| !t.is_empty()).collect();
+    let matches_token = |token: &str| {
+        token == "전체"
+            || token == track
+            || (!college.is_empty() && catalog::canonical_college(token, as_of) == college)
+            || (!department.is_empty() && department_matches(token, program, as_of))
+    };
+    let excluded = tokens
+        .iter()
+        .filter_map(|token| token.strip_suffix("제외").map(str::trim))
+        .any(matches_token);
+    !excluded
+        && tokens
+            .iter()
+            .filter(|token| !token.ends_with("제외"))
+            .any(|token| matches_token(token))
+}
+
+pub(crate) fn cohort_range(label: &str) -> Option<(i64, i64)> {
+    let label = label.trim();
+    let (start, rest) = label.split_at(label.find(|c: char| !c.is_ascii_digit())?);
+    let start = start.parse::<i64>().ok()?;
+    let end = rest
+        .strip_prefix('~')
+        .and_then(|tail| tail.get(..4))
+        .and_then(|year| year.parse::<i64>().ok())
+        .unwrap_or(start);
+    Some((start, end))
+}
+
+pub(crate) fn planned_credits(admission_year: i64, as_of: Option<&str>) -> Option<(String, i64, String)> {
+    let table = table::extract_tables(PATH_LIBERAL_REQUIRED, as_of)
+        .into_iter()
+        .find(|table| table.has_columns(&["구분"]) && table.headers.iter().any(|h| cohort_range(h).is_some()))?;
+    let row = table
+        .rows
+        .iter()
+        .find(|row| table.cell(row, "구분") == Some("편성학점"))?;
+
+    let mut columns: Vec<(usize, (i64, i64))> = table
+        .headers
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, header)| cohort_range(header).map(|range| (idx, range)))
+        .collect();
+    columns.sort_by_key(|(_, (start, _))| *start);
+    let latest = columns.last().copied();
+    let (idx, _) = columns
+        .into_iter()
+        .find(|(_, (start, end))| (*start..=*end).contains(&admission_year))
+        .or(latest.filter(|(_, (start, _))| admission_year > *start))?;
+
+    let credits = extract_first_int(row.cells.get(idx)?)?;
+    Some((table.headers[idx].clone(), credits, table.citation()))
+}
+
+pub(crate) struct CohortRequirements {
+    pub admission_year: i64,
+    pub liberal_required: Option<i64>,
+    pub elective_by_credits: bool,
+    pub summary: Value,
+}
+
+pub(crate) fn cohort_requirements(admission_year: i64, as_of: Option<&str>) -> CohortRequirements {
+    let planned = planned_credits(admission_year, as_of);
+    let own_system = elective::system_for(admission_year, as_of);
+    let choice = elective::elective_choice(admission_year, as_of);
+
+    let mut elective_systems: Vec<String> = own_system.iter().map(|system| system.section.clone()).collect();
+    if let Some(alternative) = choice
+        .as_ref()
+        .and_then(|choice| elective::system_for(choice.alternative_year, as_of))
+    {
+        if !elective_systems.contains(&alternative.section) {
+            elective_systems.push(alternative.section);
+        }
+    }
+
+    CohortRequirements {
+        admission_year,
+        liberal_required: planned.as_ref().map(|(_, credits, _)| *credits),
+        elective_by_credits: own_system.as_ref().map(|system| system.is_balance()).unwrap_or(false),
+        summary: json!({
+            "admission_year": admission_year,
+            "liberal_required": planned.as_ref().map(|(cohort, credits, citation)| {
+                json!({ "cohort": cohort, "credits": credits, "citation": citation })
+            }),
+            "liberal_elective_systems": elective_systems,
+            "liberal_elective_choice": choice.as_ref().map(|choice| {
+                json!({ "rule": choice.note, "citation": choice.citation })
+            }),
+        }),
+    }
+}
+
+pub(crate) fn curriculum_table(as_of: Option<&str>) -> Option<CorpusTable> {
+    table::find_table(PATH_LIBERAL_REQUIRED, as_of, &["학년", "과목", "시간-학점"])
+}
+
+fn load_by_track(as_of: Option<&str>, track: &str, program: &Program) -> Vec<Value> {
+    let Some(table) = table::find_table(PATH_LIBERAL_REQUIRED, as_of, &["학년", "계열", "1학기", "2학기"]) else {
+        return vec![];
+    };
+    table
+        .rows
+        .iter()
+        .filter(|row| {
+            table
+                .cell(row, "계열")
+                .map(|cell| audience_matches(cell, track, program, as_of))
+                .unwrap_or(false)
+        })
+        .map(|row| {
+            json!({
+                "학년": table.cell(row, "학년").and_then(extract_first_int),
+                "계열": table.cell(row, "계열"),
+                "1학기": table.cell(row, "1학기"),
+                "2학기": table.cell(row, "2학기"),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn planned_semester_load(
+    track: &str,
+    program: &Program,
+    year_level: i64,
+    term: &str,
+    as_of: Option<&str>,
+) -> Option<(i64, String)> {
+    let table = table::find_table(PATH_LIBERAL_REQUIRED, as_of, &["학년", "계열", "1학기", "2학기"])?;
+    let loads: Vec<i64> = table
+        .rows
+        .iter()
+        .filter(|row| table.cell(row, "학년").and_then(extract_first_int) == Some(year_level))
+        .filter(|row| {
+            table
+                .cell(row, "계열")
+                .map(|cell| audience_matches(cell, track, program, as_of))
+                .unwrap_or(false)
+        })
+        .filter_map(|row| table.cell(row, term).and_then(extract_first_int))
+        .collect();
+    if loads.is_empty() {
+        return None;
+    }
+    Some((loads.iter().sum(), table.citation()))
+}
+
+pub(crate) fn check_required_courses(
+    completed: &[String],
+    track: &str,
+    college: &str,
+    department: &str,
+    admission_year: i64,
+    as_of: Option<&str>,
+) -> Value {
+    let program = catalog::resolve(college, department, as_of);
+    let planned = planned_credits(admission_year, as_of);
+    let planned_json = planned.as_ref().map(|(cohort, credits, citation)| {
+        json!({ "cohort": cohort, "credits": credits, "citation": citation })
+    });
+
+    let Some(table) = curriculum_table(as_of) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "교양 필수 교과과정 표를 찾지 못함",
+            "citation": "(교양 필수.pdf, p.1)"
+        });
+    };
+    let curriculum_from = cohort_range(&table.section).map(|(start, _)| start).unwrap_or(2023);
+    if admission_year < curriculum_from {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": format!(
+                "코퍼스에는 {curriculum_from}학년도 이후 입학자 교양필수 과목표만 있음({admission_year}학년도 입학자 과목표 없음)"
+            ),
+            "admission_year": admission_year,
+            "planned_credits": planned_json,
+            "citation": table.citation(),
+            "notes": ["입학년도 기준 교양필수 과목표(교과과정 PDF)로 확인 필요"]
+        });
+    }
+
+    let completed_keys: Vec<String> = completed.iter().map(|name| course_key(name)).collect();
+    let mut matched_keys: Vec<String> = Vec::new();
+    let mut courses = Vec::new();
+    let mut missing = Vec::new();
+    let mut missing_credits = 0;
+
+    for row in &table.rows {
+        let Some(name) = table.cell(row, "과목") else {
+            continue;
+        };
+        let credits = table
+            .cell(row, "시간-학점")
+            .and_then(|cell| cell.rsplit('-').next())
+            .and_then(extract_first_int)
+            .unwrap_or(0);
+        let year = table.cell(row, "학년").and_then(extract_first_int);
+        let terms: Vec<&str> = ["1학기", "2학기"]
+            .into_iter()
+            .filter(|term| {
+                table
+                    .cell(row, &format!("{term} 수강 대상"))
+                    .map(|cell| audience_matches(cell, track, &program, as_of))
+                    .unwrap_or(false)
+            })
+            .collect();
+        let schedule = match (year, terms.as_slice()) {
+            (Some(year), [term, ..]) => format!("{year}학년 {term}"),
+            (Some(year), []) if track == "융특" => format!("{year}학년 이후 소속 전공의 수강 학기"),
+            (Some(year), []) => format!("{year}학년 (계열/대학 정보로 학기 판별 불가)"),
+            (None, _) => "판별 불가".to_string(),
+        };
+
+        let key = course_key(name);
+        let completed = completed_keys.contains(&key);
+        if completed {
+            matched_keys.push(key);
+        } else {
+            missing.push(name.to_string());
+            missing_credits += credits;
+        }
+        courses.push(json!({
+            "course": name,
+            "credits": credits,
+            "year": year,
+            "scheduled": schedule,
+            "completed": completed,
+        }));
+    }
+
+    let unmatched: Vec<&String> = completed
+        .iter()
+        .zip(completed_keys.iter())
+        .filter(|(_, key)| !matched_keys.contains(key))
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut notes = vec![
+        "과목명 공백/기호를 무시하고 교과과정 표의 과목명과 비교".to_string(),
+    ];
+    if track == "융특" {
+        notes.push("융합특성화자유전공학부 입학자는 2학년 이후 소속 전공에 따라 글로벌시민의식, 글로벌소통과언어, 창의적사고와혁신을 이수 (교양 필수.pdf, p.1)".to_string());
+    }
+    if program.college.is_empty() {
+        notes.push("창의적사고와혁신 수강 학기는 소속 대학(college)으로 판별".to_string());
+    }
+    if !unmatched.is_empty() {
+        notes.push("과목표에 없는 과목은 교양필수 인정 여부를 학과/교양교육 운영 부서에 확인 필요".to_string());
+    }
+
+    json!({
+        "judgement": if missing.is_empty() { "가능" } else { "불가" },
+        "admission_year": admission_year,
+        "track": track,
+        "college": program.college,
+        "department": program.department,
+        "program": program.summary(),
+        "curriculum": table.section,
+        "planned_credits": planned_json,
+        "required_courses": courses,
+        "missing_courses": missing,
+        "missing_credits": missing_credits,
+        "unmatched_completed": unmatched,
+        "semester_load": load_by_track(as_of, track, &program),
+        "citation": table.citation(),
+        "notes": notes,
+    })
+}
@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::catalog;
+use crate::liberal;
+use crate::transcript::classify_category;
+use crate::{extract_first_int, numeric_rule, optional_i64, optional_string, required_i64, required_string};
+
+static TERM_LABEL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{4})\s*(?:년도|년|-|\.|/)?\s*(1|2|여름|겨울|하계|동계|[SsWw])")
+        .expect("TERM_LABEL_RE compile failure")
+});
+
+pub(crate) const LEVELS: &[&str] = &["학사", "대학원"];
+pub(crate) const GRADUATE_ALLOWANCES: &[&str] = &["기본", "예외", "연수과정"];
+
+pub(crate) struct PlannedCourse {
+    pub name: String,
+    pub credits: i64,
+    pub category: Option<String>,
+}
+
+pub(crate) struct PlannedSemester {
+    pub label: String,
+    pub year_level: Option<i64>,
+    pub courses: Vec<PlannedCourse>,
+}
+
+impl PlannedSemester {
+    fn credits(&self) -> i64 {
+        self.courses.iter().map(|course| course.credits).sum()
+    }
+
+    fn term(&self) -> Option<(i64, &'static str)> {
+        parse_term(&self.label)
+    }
+}
+
+pub(crate) fn parse_term(label: &str) -> Option<(i64, &'static str)> {
+    let caps = TERM_LABEL_RE.captures(label.trim())?;
+    let year = caps[1].parse().ok()?;
+    let term = match &caps[2] {
+        "1" => "1학기",
+        "2" => "2학기",
+        "여름" | "하계" | "S" | "s" => "여름학기",
+        _ => "겨울학기",
+    };
+    Some((year, term))
+}
+
+pub(crate) fn normalize_level(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "학사" | "학부" | "학사과정" => Some("학사"),
+        "대학원" | "석사" | "박사" | "석박통합" | "대학원과정" => Some("대학원"),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_semesters(args: &Map<String, Value>) -> std::result::Result<Vec<PlannedSemester>, String> {
+    let items = args
+        .get("semesters")
+        .and_then(Value::as_array)
+        .ok_or("'semesters' is required and must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("semesters[{index}] must be an object"))?;
+            let prefixed = |message: String| format!("semesters[{index}]: {message}");
+            let courses = object
+                .get("courses")
+                .and_then(Value::as_array)
+                .ok_or_else(|| prefixed("'courses' is required and must be an array".to_string()))?
+                .iter()
+                .enumerate()
+                .map(|(course_index, course)| {
+                    let course = course.as_object().ok_or_else(|| {
+                        format!("semesters[{index}].courses[{course_index}] must be an object")
+                    })?;
+                    let prefixed =
+                        |message: String| format!("semesters[{index}].courses[{course_index}]: {message}");
+                    let credits = required_i64(course, "credits").map_err(prefixed)?;
+                    if credits <= 0 {
+                        return Err(prefixed("'credits' must be a positive integer".to_string()));
+                    }
+                    Ok(PlannedCourse {
+                        name: required_string(course, "name").map_err(prefixed)?.trim().to_string(),
+                        credits,
+                        category: optional_string(course, "category").map(|category| category.trim().to_string()),
+                    })
+                })
+                .collect::<std::result::Result<Vec<_>, String>>()?;
+            Ok(PlannedSemester {
+                label: required_string(object, "semester").map_err(prefixed)?.trim().to_string(),
+                year_level: optional_i64(object, "year_level"),
+                courses,
+            })
+        })
+        .collect()
+}
+
+fn is_prerequisite(course: &PlannedCourse) -> bool {
+    course
+        .category
+        .as_deref()
+        .map(|category| category.contains("선수"))
+        .unwrap_or(false)
+}
+
+fn graduate_cap(allowance: &str, as_of: Option<&str>) -> Option<(i64, String)> {
+    let label = match allowance {
+        "예외" => "대학원 학기당 이수학점(예외)",
+        "연수과정" => "특정 연수과정 예외",
+        _ => "대학원 학기당 이수학점(기본)",
+    };
+    let (value, citation) = numeric_rule(label, as_of)?;
+    Some((extract_first_int(&value)?, citation))
+}
+
+fn validate_graduate(semesters: &[PlannedSemester], allowance: &str, as_of: Option<&str>) -> Value {
+    let Some((cap, citation)) = graduate_cap(allowance, as_of) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 대학원 학기당 이수학점 기준을 찾지 못함",
+            "citation": "(학칙.pdf, p.10, 제44조)"
+        });
+    };
+
+    let mut violations = Vec::new();
+    let results: Vec<Value> = semesters
+        .iter()
+        .map(|semester| {
+            let prerequisites: Vec<&PlannedCourse> =
+                semester.courses.iter().filter(|course| is_prerequisite(course)).collect();
+            let prerequisite: i64 = prerequisites.iter().map(|course| course.credits).sum();
+            let counted = semester.credits() - prerequisite;
+            if counted > cap {
+                violations.push(format!(
+                    "{}: 학기당 {counted}학점으로 한도 {cap}학점 초과 {citation}",
+                    semester.label
+                ));
+            }
+            json!({
+                "semester": semester.label,
+                "credits": semester.credits(),
+                "prerequisite_courses": prerequisites.iter().map(|course| course.name.as_str()).collect::<Vec<_>>(),
+                "prerequisite_credits": prerequisite,
+                "counted_credits": counted,
+                "cap": cap,
+                "over": (counted - cap).max(0),
+            })
+        })
+        .collect();
+
+    let mut notes = vec![
+        "선수과목(category에 '선수' 포함)은 학기당 이수학점을 초과하여 이수 가능 (학칙.pdf, p.10, 제44조 3항)".to_string(),
+    ];
+    if allowance == "기본" {
+        notes.push(
+            "지도교수 승인(일반대학원) 등 제44조 2항 사유가 있으면 allowance=예외(12학점)로 다시 확인".to_string(),
+        );
+    }
+    json!({
+        "judgement": if violations.is_empty() { "가능" } else { "불가" },
+        "level": "대학원",
+        "allowance": allowance,
+        "semesters": results,
+        "violations": violations,
+        "citation": citation,
+        "notes": notes,
+    })
+}
+
+fn validate_undergraduate(
+    semesters: &[PlannedSemester],
+    track: Option<&str>,
+    college: &str,
+    department: &str,
+    as_of: Option<&str>,
+) -> Value {
+    let Some((cap, citation)) = numeric_rule("학사 연간 이수학점", as_of)
+        .and_then(|(value, citation)| Some((extract_first_int(&value)?, citation)))
+    else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 학사 연간 이수학점 기준을 찾지 못함",
+            "citation": "(학칙.pdf, p.10, 제43조 1항)"
+        });
+    };
+    let program = catalog::resolve(college, department, as_of);
+
+    let mut violations = Vec::new();
+    let mut warnings = Vec::new();
+    let mut unparsed = Vec::new();
+    let mut annual: BTreeMap<i64, (i64, i64, Vec<String>)> = BTreeMap::new();
+    let mut results = Vec::new();
+
+    for semester in semesters {
+        let credits = semester.credits();
+        let mut entry = json!({
+            "semester": semester.label,
+            "year_level": semester.year_level,
+            "credits": credits,
+        });
+        let Some((academic_year, term)) = semester.term() else {
+            unparsed.push(semester.label.clone());
+            results.push(entry);
+            continue;
+        };
+        entry["academic_year"] = json!(academic_year);
+        entry["term"] = json!(term);
+
+        let (regular, seasonal, labels) = annual.entry(academic_year).or_default();
+        labels.push(semester.label.clone());
+        if matches!(term, "여름학기" | "겨울학기") {
+            *seasonal += credits;
+        } else {
+            *regular += credits;
+        }
+
+        let liberal_required: i64 = semester
+            .courses
+            .iter()
+            .filter(|course| course.category.as_deref().map(classify_category) == Some("교양필수"))
+            .map(|course| course.credits)
+            .sum();
+        if let (Some(track), Some(year_level), "1학기" | "2학기") = (track, semester.year_level, term) {
+            let planned = liberal::planned_semester_load(track, &program, year_level, term, as_of);
+            if let Some((planned_credits, planned_citation)) = &planned {
+                if liberal_required > *planned_credits {
+                    warnings.push(format!(
+                        "{}: 교양필수 {liberal_required}학점이 {track} {year_level}학년 {term} 편성 {planned_credits}학점보다 많음 {planned_citation}",
+                        semester.label
+                    ));
+                }
+            }
+            entry["liberal_required"] = json!({
+                "credits": liberal_required,
+                "planned": planned.as_ref().map(|(credits, _)| credits),
+                "citation": planned.as_ref().map(|(_, citation)| citation),
+            });
+        }
+        results.push(entry);
+    }
+
+    let annual_results: Vec<Value> = annual
+        .iter()
+        .map(|(academic_year, (regular, seasonal, labels))| {
+            if *regular > cap {
+                violations.push(format!(
+                    "{academic_year}학년도: 정규학기 합계 {regular}학점으로 연간 {cap}학점 초과 {citation}"
+                ));
+            }
+            json!({
+                "academic_year": academic_year,
+                "semesters": labels,
+                "regular_credits": regular,
+                "seasonal_credits": seasonal,
+                "cap": cap,
+                "over": (regular - cap).max(0),
+                "remaining": (cap - regular).max(0),
+            })
+        })
+        .collect();
+
+    let mut notes = vec![
+        "학년도는 3월 1일부터 다음 해 2월 말까지 (학칙.pdf, p.4, 제13조)".to_string(),
+        "학기별 세부 상한과 계절학기 산입 여부는 시행세칙 소관으로 코퍼스에 없어 정규학기 합계만 연간 한도와 비교".to_string(),
+        "순수외국인 입학생의 학기당 이수학점은 총장이 따로 정함 (학칙.pdf, p.10, 제43조 1항)".to_string(),
+    ];
+    if track.is_none() {
+        notes.push("track(인문사회/이공/융특)과 year_level을 주면 교양필수 계열별 학기 편성학점과 비교".to_string());
+    }
+    if !unparsed.is_empty() {
+        notes.push(format!(
+            "학기 표기를 해석하지 못해 연간 합계에서 제외: {} (예: 2026-1, 2026-2, 2026-여름)",
+            unparsed.join(", ")
+        ));
+    }
+
+    let judgement = if !violations.is_empty() {
+        "불가"
+    } else if unparsed.is_empty() {
+        "가능"
+    } else {
+        "판정 불가"
+    };
+    json!({
+        "judgement": judgement,
+        "level": "학사",
+        "track": track,
+        "program": program.summary(),
+        "semesters": results,
+        "annual": annual_results,
+        "violations": violations,
+        "warnings": warnings,
+        "citation": citation,
+        "notes": notes,
+    })
+}
+
+pub(crate) fn validate_course_load(
+    semesters: &[PlannedSemester],
+    level: &str,
+    allowance: &str,
+    track: Option<&str>,
+    college: &str,
+    department: &str,
+    as_of: Option<&str>,
+) -> Value {
+    match level {
+        "대학원" => validate_graduate(semesters, allowance, as_of),
+        _ => validate_undergraduate(semesters, track, college, department, as_of),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(name: &str, credits: i64, category: Option<&str>) -> PlannedCourse {
+        PlannedCourse {
+            name: name.to_string(),
+            credits,
+            category: category.map(str::to_string),
+        }
+    }
+
+    fn graduate_semester(extra: Option<PlannedCourse>) -> Vec<PlannedSemester> {
+        let mut courses = vec![
+            course("고급알고리즘", 3, Some("전공")),
+            course("분산시스템", 3, Some("전공")),
+            course("기계학습특론", 3, Some("전공")),
+            course("데이터베이스", 3, Some("선수과목")),
+            course("연구방법론", 3, None),
+        ];
+        courses.extend(extra);
+        vec![PlannedSemester {
+            label: "2025-1".to_string(),
+            year_level: None,
+            courses,
+        }]
+    }
+
+    fn judge(semesters: &[PlannedSemester], allowance: &str) -> Value {
+        validate_course_load(semesters, "대학원", allowance, None, "", "", None)
+    }
+
+    #[test]
+    fn graduate_allowances_cap_at_9_12_and_15_credits() {
+        let semesters = graduate_semester(None);
+
+        let basic = judge(&semesters, "기본");
+        assert_eq!(basic["judgement"], "불가");
+        assert_eq!(basic["semesters"][0]["cap"], 9);
+        assert_eq!(basic["semesters"][0]["counted_credits"], 12);
+        assert_eq!(basic["semesters"][0]["prerequisite_credits"], 3);
+        assert_eq!(basic["semesters"][0]["over"], 3);
+        assert_eq!(basic["citation"], "(학칙.pdf, p.10, 제44조 1항)");
+
+        let exception = judge(&semesters, "예외");
+        assert_eq!(exception["judgement"], "가능");
+        assert_eq!(exception["semesters"][0]["cap"], 12);
+
+        let training = judge(&graduate_semester(Some(course("세미나", 3, Some("전공")))), "연수과정");
+        assert_eq!(training["judgement"], "가능");
+        assert_eq!(training["semesters"][0]["cap"], 15);
+        assert_eq!(training["semesters"][0]["counted_credits"], 15);
+        assert_eq!(training["citation"], "(학칙.pdf, p.10, 제44조 4항)");
+
+        let over = judge(&graduate_semester(Some(course("세미나", 4, Some("전공")))), "연수과정");
+        assert_eq!(over["judgement"], "불가");
+        assert_eq!(over["semesters"][0]["over"], 1);
+    }
+
+    #[test]
+    fn term_labels_accept_common_forms() {
+        assert_eq!(parse_term("2025-1"), Some((2025, "1학기")));
+        assert_eq!(parse_term("2025년 2학기"), Some((2025, "2학기")));
+        assert_eq!(parse_term("2025 하계"), Some((2025, "여름학기")));
+        assert_eq!(parse_term("2025-W"), Some((2025, "겨울학기")));
+        assert_eq!(parse_term("1학기"), None);
+    }
+}
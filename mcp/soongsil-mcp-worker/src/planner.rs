@@ -0,0 +1,403 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::load::parse_term;
+use crate::{catalog, evaluate_credit_totals, extract_first_int, liberal, numeric_rule, standing, CreditTotals};
+
+static SCHEDULE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d)학년 (\d)학기").expect("SCHEDULE_RE compile failure"));
+
+pub(crate) struct PlanRequest<'a> {
+    pub college: &'a str,
+    pub department: &'a str,
+    pub major_type: &'a str,
+    pub earned: &'a CreditTotals,
+    pub admission_year: Option<i64>,
+    pub completed_semesters: i64,
+    pub next_semester: Option<&'a str>,
+    pub credits_this_year: i64,
+    pub semester_cap: Option<i64>,
+    pub track: Option<&'static str>,
+    pub completed_courses: &'a [String],
+}
+
+struct PlannedTerm {
+    label: String,
+    academic_year: i64,
+    year_level: i64,
+    term: i64,
+}
+
+struct Placement {
+    name: String,
+    credits: i64,
+    scheduled: String,
+    slot: Option<usize>,
+}
+
+fn div_ceil(value: i64, by: i64) -> i64 {
+    if by <= 0 {
+        value
+    } else {
+        (value + by - 1) / by
+    }
+}
+
+fn rule_value(label: &str, as_of: Option<&str>) -> Option<(i64, String)> {
+    let (value, citation) = numeric_rule(label, as_of)?;
+    Some((extract_first_int(&value)?, citation))
+}
+
+fn planned_terms(request: &PlanRequest, remaining: i64) -> Vec<PlannedTerm> {
+    let start = request
+        .next_semester
+        .and_then(parse_term)
+        .and_then(|(year, term)| match term {
+            "1학기" => Some((year, 0)),
+            "2학기" => Some((year, 1)),
+            _ => None,
+        });
+    let first_offset = start
+        .map(|(_, offset)| offset)
+        .unwrap_or(request.completed_semesters % 2);
+
+    (0..remaining)
+        .map(|k| {
+            let index = request.completed_semesters + k;
+            let position = first_offset + k;
+            let term = position % 2 + 1;
+            let (academic_year, label) = match start {
+                Some((year, _)) => {
+                    let academic_year = year + position / 2;
+                    (academic_year, format!("{academic_year}-{term}"))
+                }
+                None => (position / 2, format!("남은 {}번째 학기", k + 1)),
+            };
+            PlannedTerm {
+                label,
+                academic_year,
+                year_level: index / 2 + 1,
+                term,
+            }
+        })
+        .collect()
+}
+
+fn required_placements(request: &PlanRequest, terms: &[PlannedTerm], as_of: Option<&str>) -> Vec<Placement> {
+    let (Some(track), Some(admission_year)) = (request.track, request.admission_year) else {
+        return vec![];
+    };
+    let check = liberal::check_required_courses(
+        request.completed_courses,
+        track,
+        request.college,
+        request.department,
+        admission_year,
+        as_of,
+    );
+    let Some(courses) = check["required_courses"].as_array() else {
+        return vec![];
+    };
+    courses
+        .iter()
+        .filter(|course| course["completed"] == json!(false))
+        .map(|course| {
+            let scheduled = course["scheduled"].as_str().unwrap_or_default().to_string();
+            let slot = SCHEDULE_RE.captures(&scheduled).and_then(|caps| {
+                let year: i64 = caps[1].parse().ok()?;
+                let term: i64 = caps[2].parse().ok()?;
+                terms
+                    .iter()
+                    .position(|planned| planned.year_level >= year && planned.term == term)
+                    .or_else(|| terms.iter().position(|planned| planned.term == term))
+            });
+            Placement {
+                name: course["course"].as_str().unwrap_or_default().to_string(),
+                credits: course["credits"].as_i64().unwrap_or(0),
+                scheduled,
+                slot: slot.or(if terms.is_empty() { None } else { Some(0) }),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn plan_graduation(request: &PlanRequest, as_of: Option<&str>) -> Value {
+    let audit = evaluate_credit_totals(
+        request.college,
+        request.department,
+        request.major_type,
+        request.earned,
+        request.admission_year,
+        as_of,
+    );
+    if audit.get("gap").is_none() {
+        return json!({
+            "judgement": audit["judgement"].clone(),
+            "reason": "졸업요건 비교가 끝나지 않아 학기별 계획을 세울 수 없음",
+            "audit": audit,
+            "citation": "(학점 이수 체계.pdf, p.1)"
+        });
+    }
+
+    let program = catalog::resolve(request.college, request.department, as_of);
+    let five_year = standing::five_year_program(&program, as_of) == Some(true);
+    let semesters_label = if five_year { "건축학전공 정규등록학기" } else { "학사 정규등록학기" };
+    let (Some((regular_semesters, semesters_citation)), Some((annual_cap, cap_citation))) = (
+        rule_value(semesters_label, as_of),
+        rule_value("학사 연간 이수학점", as_of),
+    ) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 정규등록학기 또는 연간 이수학점 기준을 찾지 못함",
+            "audit": audit,
+            "citation": "(학칙.pdf, p.8, 제32조 5항)"
+        });
+    };
+    let remaining_semesters = (regular_semesters - request.completed_semesters).max(0);
+    let terms = planned_terms(request, remaining_semesters);
+    let placements = required_placements(request, &terms, as_of);
+
+    let gap = &audit["gap"];
+    let gap_of = |key: &str| gap[key].as_i64().unwrap_or(0);
+    let placed_credits: i64 = placements.iter().map(|placement| placement.credits).sum();
+    let mut needs: Vec<(String, i64)> = vec![
+        ("교양필수".to_string(), gap_of("교양필수").max(placed_credits)),
+        ("전공기초".to_string(), gap_of("전공기초")),
+        (request.major_type.to_string(), gap_of(request.major_type)),
+        ("교양선택".to_string(), gap_of("교양선택")),
+    ];
+    let category_total: i64 = needs.iter().map(|(_, need)| need).sum();
+    needs.push(("일반선택".to_string(), (gap_of("졸업학점") - category_total).max(0)));
+    let total_needed: i64 = needs.iter().map(|(_, need)| need).sum();
+
+    let mut year_budget: BTreeMap<i64, i64> = BTreeMap::new();
+    for (position, planned) in terms.iter().enumerate() {
+        year_budget.entry(planned.academic_year).or_insert_with(|| {
+            if position == 0 && planned.term == 2 {
+                (annual_cap - request.credits_this_year).max(0)
+            } else {
+                annual_cap
+            }
+        });
+    }
+    let semester_limit = |budget: i64| request.semester_cap.map_or(budget, |cap| cap.min(budget));
+    let capacity: i64 = year_budget
+        .iter()
+        .map(|(year, budget)| {
+            let count = terms.iter().filter(|planned| planned.academic_year == *year).count() as i64;
+            request.semester_cap.map_or(*budget, |cap| (cap * count).min(*budget))
+        })
+        .sum();
+
+    let mut fixed: Vec<i64> = vec![0; terms.len()];
+    for placement in &placements {
+        if let Some(slot) = placement.slot {
+            fixed[slot] += placement.credits;
+        }
+    }
+
+    let mut allocation: Vec<i64> = vec![0; terms.len()];
+    let mut remaining = total_needed;
+    let mut budget_left = year_budget.clone();
+    for (position, planned) in terms.iter().enumerate() {
+        let left = (terms.len() - position) as i64;
+        let allowed = semester_limit(budget_left[&planned.academic_year]);
+        let target = div_ceil(remaining, left).max(fixed[position]).min(allowed).min(remaining);
+        allocation[position] = target;
+        remaining -= target;
+        if let Some(budget) = budget_left.get_mut(&planned.academic_year) {
+            *budget -= target;
+        }
+    }
+    for (position, planned) in terms.iter().enumerate() {
+        if remaining <= 0 {
+            break;
+        }
+        let slack = budget_left[&planned.academic_year]
+            .min(request.semester_cap.map_or(i64::MAX, |cap| cap - allocation[position]));
+        let extra = slack.max(0).min(remaining);
+        allocation[position] += extra;
+        remaining -= extra;
+        if let Some(budget) = budget_left.get_mut(&planned.academic_year) {
+            *budget -= extra;
+        }
+    }
+
+    let mut flexible: Vec<(String, i64)> = needs
+        .iter()
+        .map(|(category, need)| {
+            let need = if category == "교양필수" { need - placed_credits } else { *need };
+            (category.clone(), need.max(0))
+        })
+        .collect();
+    let mut plan = Vec::new();
+    for (position, planned) in terms.iter().enumerate() {
+        let left = (terms.len() - position) as i64;
+        let fixed_here = fixed[position].min(allocation[position]);
+        let mut amount = allocation[position] - fixed_here;
+        let mut by_category: Map<String, Value> = Map::new();
+        let mut shares: Vec<i64> = vec![0; flexible.len()];
+        for (idx, (_, need)) in flexible.iter().enumerate() {
+            let share = div_ceil(*need, left).min(amount);
+            shares[idx] = share;
+            amount -= share;
+        }
+        for (idx, (_, need)) in flexible.iter().enumerate() {
+            let extra = (need - shares[idx]).max(0).min(amount);
+            shares[idx] += extra;
+            amount -= extra;
+        }
+        for (idx, (category, need)) in flexible.iter_mut().enumerate() {
+            let mut credits = shares[idx];
+            *need -= shares[idx];
+            if category == "교양필수" {
+                credits += fixed_here;
+            }
+            if credits > 0 {
+                by_category.insert(category.clone(), json!(credits));
+            }
+        }
+        plan.push(json!({
+            "semester": planned.label,
+            "year_level": planned.year_level,
+            "term": format!("{}학기", planned.term),
+            "credits": allocation[position],
+            "by_category": by_category,
+            "required_courses": placements
+                .iter()
+                .filter(|placement| placement.slot == Some(position))
+                .map(|placement| json!({
+                    "course": placement.name,
+                    "credits": placement.credits,
+                    "scheduled": placement.scheduled,
+                }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+
+    let feasible = total_needed <= capacity && remaining <= 0;
+    let shortfall = (total_needed - capacity).max(0);
+    let per_semester = request.semester_cap.unwrap_or(annual_cap / 2).max(1);
+    let unverified = audit["unverified"].as_array().cloned().unwrap_or_default();
+    let judgement = if !feasible {
+        "불가"
+    } else if unverified.is_empty() {
+        "가능"
+    } else {
+        "판정 불가"
+    };
+
+    let mut notes = vec![
+        format!("정규등록학기 {regular_semesters}학기 안에서 계획 {semesters_citation}"),
+        format!("학년도(3월~다음 해 2월)별 정규학기 합계를 연간 {annual_cap}학점 이내로 배분 {cap_citation}"),
+        "학기별 상한과 계절학기 산입 기준은 시행세칙 소관으로 코퍼스에 없음: semester_cap으로 학기 상한을 지정 가능".to_string(),
+        "휴학 없이 연속 등록한다고 가정".to_string(),
+    ];
+    if request.track.is_none() || request.admission_year.is_none() {
+        notes.push("track과 admission_year를 주면 교양필수 미이수 과목을 교양 필수 문서의 수강 학기에 배치".to_string());
+    }
+    if placements.iter().any(|placement| placement.slot.is_none()) {
+        notes.push("남은 학기가 없어 배치하지 못한 교양필수 과목이 있음".to_string());
+    }
+    if !unverified.is_empty() {
+        notes.push("교양선택은 영역/과목 기준이므로 ssu_check_liberal_elective로 별도 확인 필요".to_string());
+    }
+    if !feasible {
+        notes.push(format!(
+            "정규등록학기 안 졸업 불가: {shortfall}학점 부족, 학기당 {per_semester}학점 기준 약 {}개 학기 추가 필요",
+            div_ceil(shortfall, per_semester)
+        ));
+    }
+
+    json!({
+        "judgement": judgement,
+        "on_time": feasible,
+        "regular_semesters": {
+            "total": regular_semesters,
+            "completed": request.completed_semesters,
+            "remaining": remaining_semesters,
+            "five_year_program": five_year,
+            "citation": semesters_citation,
+        },
+        "annual_cap": { "credits": annual_cap, "citation": cap_citation },
+        "semester_cap": request.semester_cap,
+        "needed": needs.iter().map(|(category, need)| (category.clone(), json!(need))).collect::<Map<String, Value>>(),
+        "total_needed": total_needed,
+        "capacity": capacity,
+        "shortfall": shortfall,
+        "plan": plan,
+        "unverified": unverified,
+        "audit": audit,
+        "citation": semesters_citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARNED: CreditTotals = CreditTotals {
+        liberal_required: 19,
+        liberal_elective: 9,
+        major_basic: 15,
+        major: 30,
+        total: 73,
+    };
+
+    fn request(completed_semesters: i64, next_semester: &'static str, credits_this_year: i64) -> PlanRequest<'static> {
+        PlanRequest {
+            college: "IT대학",
+            department: "컴퓨터학부",
+            major_type: "단일전공자",
+            earned: &EARNED,
+            admission_year: None,
+            completed_semesters,
+            next_semester: Some(next_semester),
+            credits_this_year,
+            semester_cap: None,
+            track: None,
+            completed_courses: &[],
+        }
+    }
+
+    #[test]
+    fn spreads_remaining_credits_within_annual_budget() {
+        let result = plan_graduation(&request(4, "2026-1", 0), None);
+        assert_eq!(result["judgement"], "가능");
+        assert_eq!(result["annual_cap"]["credits"], 38);
+        assert_eq!(result["total_needed"], 60);
+        assert_eq!(result["capacity"], 76);
+        let credits: Vec<i64> = result["plan"]
+            .as_array()
+            .expect("plan")
+            .iter()
+            .map(|term| term["credits"].as_i64().unwrap_or_default())
+            .collect();
+        assert_eq!(credits, vec![15, 15, 15, 15]);
+    }
+
+    #[test]
+    fn second_term_budget_subtracts_credits_already_taken() {
+        let result = plan_graduation(&request(7, "2026-2", 30), None);
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["capacity"], 8);
+        assert_eq!(result["shortfall"], 52);
+        assert_eq!(result["plan"][0]["semester"], "2026-2");
+        assert_eq!(result["plan"][0]["credits"], 8);
+    }
+
+    #[test]
+    fn no_remaining_regular_semesters_leaves_the_whole_gap() {
+        let result = plan_graduation(&request(8, "2026-1", 0), None);
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["regular_semesters"]["remaining"], 0);
+        assert_eq!(result["on_time"], false);
+        assert_eq!(result["capacity"], 0);
+        assert_eq!(result["shortfall"], 60);
+        assert_eq!(result["plan"], json!([]));
+    }
+}
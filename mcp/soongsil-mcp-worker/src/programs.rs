@@ -0,0 +1,327 @@
+use serde_json::{json, Map, Value};
+
+use crate::catalog::{self, Scope};
+use crate::{
+    evaluate_credit_totals, extract_first_int, get_file_text, judgement_rank, load_credit_rows,
+    match_credit_row, optional_string, required_count, required_string, row_string, skipped_credit_rows,
+    CreditTotals, PATH_CREDIT_SYSTEM,
+};
+
+pub(crate) struct SecondaryProgram {
+    pub college: String,
+    pub department: String,
+    pub program_type: &'static str,
+    pub earned: i64,
+}
+
+impl SecondaryProgram {
+    fn column(&self) -> &'static str {
+        match self.program_type {
+            "복수전공" => "복수전공자(복수전공)",
+            _ => "부전공자",
+        }
+    }
+}
+
+fn normalize_program_type(raw: &str) -> Option<&'static str> {
+    match raw.trim() {
+        "복수전공" | "복전" | "복수전공자" | "복수전공자(복수전공)" => Some("복수전공"),
+        "부전공" | "부전" | "부전공자" => Some("부전공"),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_secondary_programs(
+    args: &Map<String, Value>,
+) -> std::result::Result<Vec<SecondaryProgram>, String> {
+    let items = args
+        .get("secondary_programs")
+        .and_then(Value::as_array)
+        .ok_or("'secondary_programs' is required and must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("secondary_programs[{index}] must be an object"))?;
+            let prefixed = |message: String| format!("secondary_programs[{index}]: {message}");
+            let raw_type = required_string(object, "program_type").map_err(prefixed)?;
+            Ok(SecondaryProgram {
+                college: required_string(object, "college").map_err(prefixed)?.trim().to_string(),
+                department: optional_string(object, "department")
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+                program_type: normalize_program_type(&raw_type)
+                    .ok_or_else(|| prefixed("'program_type' must be 복수전공 or 부전공".to_string()))?,
+                earned: required_count(object, "earned_credits").map_err(prefixed)?,
+            })
+        })
+        .collect()
+}
+
+fn regulation_memo(as_of: Option<&str>) -> Vec<String> {
+    let Some(text) = get_file_text(PATH_CREDIT_SYSTEM, as_of) else {
+        return vec![];
+    };
+    text.lines()
+        .map(str::trim)
+        .skip_while(|line| !(line.starts_with('#') && line.contains("원문 규정 메모")))
+        .skip(1)
+        .take_while(|line| !line.starts_with('#'))
+        .filter_map(|line| line.strip_prefix("- "))
+        .map(str::to_string)
+        .collect()
+}
+
+fn memo_applies(memo: &str, departments: &[&str], has_double: bool) -> bool {
+    if memo.contains("건축학") {
+        return departments.iter().any(|department| department.contains("건축"));
+    }
+    if memo.contains("복수전공자") {
+        return has_double;
+    }
+    memo.contains("다전공")
+}
+
+fn secondary_label(program: &catalog::Program) -> String {
+    if program.department.is_empty() {
+        program.college.clone()
+    } else {
+        program.label()
+    }
+}
+
+fn worse<'a>(left: &'a str, right: &'a str) -> &'a str {
+    if judgement_rank(right) < judgement_rank(left) {
+        right
+    } else {
+        left
+    }
+}
+
+pub(crate) fn evaluate_programs(
+    college: &str,
+    department: &str,
+    earned: &CreditTotals,
+    secondaries: &[SecondaryProgram],
+    admission_year: Option<i64>,
+    as_of: Option<&str>,
+) -> Value {
+    let has_double = secondaries.iter().any(|program| program.program_type == "복수전공");
+    let primary_column = if has_double { "복수전공자(주전공)" } else { "단일전공자" };
+
+    let mut result = evaluate_credit_totals(college, department, primary_column, earned, admission_year, as_of);
+    let mut judgement = result["judgement"].as_str().unwrap_or("판정 불가").to_string();
+    let rows = load_credit_rows(as_of);
+    let primary = catalog::resolve(college, department, as_of);
+
+    let mut programs = Vec::new();
+    let mut departments = vec![primary.department.clone()];
+    for program in secondaries {
+        let column = program.column();
+        let resolved = catalog::resolve(&program.college, &program.department, as_of);
+        departments.push(resolved.department.clone());
+        let mut entry = json!({
+            "college": program.college,
+            "department": program.department,
+            "program_type": program.program_type,
+            "column": column,
+            "earned": program.earned,
+            "program": resolved.summary(),
+        });
+
+        if !resolved.department.is_empty() && resolved.department == primary.department {
+            entry["allowed"] = json!(false);
+            entry["reason"] = json!("주전공과 같은 학과(부)는 복수전공/부전공 대상이 될 수 없음");
+            judgement = worse(&judgement, "불가").to_string();
+            programs.push(entry);
+            continue;
+        }
+
+        let Some(matched) = match_credit_row(&rows, &resolved) else {
+            entry["allowed"] = Value::Null;
+            entry["reason"] = json!("학점 이수 체계 표에서 일치하는 대학/학과 행을 찾지 못함");
+            judgement = worse(&judgement, "판정 불가").to_string();
+            programs.push(entry);
+            continue;
+        };
+        let row = &matched.row;
+        entry["matched_rule"] = json!(row.values);
+        entry["row_match"] = matched.summary();
+
+        let raw_requirement = row_string(row, column);
+        if raw_requirement.trim() == "불허" {
+            entry["allowed"] = json!(false);
+            entry["reason"] = json!(format!(
+                "{} {}의 {} 칸이 불허",
+                row_string(row, "대학"),
+                row_string(row, "학과/학부"),
+                column
+            ));
+            judgement = worse(&judgement, "불가").to_string();
+            programs.push(entry);
+            continue;
+        }
+
+        let required = extract_first_int(&raw_requirement).unwrap_or(0);
+        let gap = (required - program.earned).max(0);
+        entry["allowed"] = json!(true);
+        entry["required"] = json!(required);
+        entry["gap"] = json!(gap);
+        if gap > 0 {
+            judgement = worse(&judgement, "불가").to_string();
+        }
+        if let Some(gaps) = result.get_mut("gap").and_then(Value::as_object_mut) {
+            gaps.insert(
+                format!("{} {}", secondary_label(&resolved), column),
+                json!(gap),
+            );
+        }
+        programs.push(entry);
+    }
+
+    let departments: Vec<&str> = departments.iter().map(String::as_str).collect();
+    let memo: Vec<String> = regulation_memo(as_of)
+        .into_iter()
+        .filter(|memo| memo_applies(memo, &departments, has_double))
+        .collect();
+
+    result["judgement"] = json!(judgement);
+    result["primary"] = json!({
+        "college": primary.college,
+        "department": primary.department,
+        "column": primary_column,
+    });
+    result["secondary_programs"] = json!(programs);
+    result["applied_regulations"] = json!(memo);
+    if let Some(notes) = result.get_mut("notes").and_then(Value::as_array_mut) {
+        notes.push(json!("원문 규정 메모 (학점 이수 체계.pdf, p.1) 기준으로 불허 칸과 다전공 조건을 확인"));
+        if !has_double && !secondaries.is_empty() {
+            notes.push(json!(
+                "부전공만 있는 경우 주전공 기준 학점의 별도 표기가 없어 단일전공자 기준을 적용"
+            ));
+        }
+    }
+    result
+}
+
+pub(crate) fn list_programs(college_filter: Option<&str>, as_of: Option<&str>) -> Value {
+    let rows = load_credit_rows(as_of);
+    let departments = catalog::departments(as_of);
+    let filter = college_filter.map(|raw| catalog::canonical_college(raw, as_of));
+    let mut colleges: Vec<(String, Vec<Value>)> = Vec::new();
+
+    for row in &rows {
+        let college = row_string(row, "대학");
+        if let Some(filter) = &filter {
+            if !college.contains(filter.as_str()) {
+                continue;
+            }
+        }
+        let label = row_string(row, "학과/학부");
+        let scope = catalog::row_scope(&label);
+        let (listed, excludes) = match &scope {
+            Scope::All => (vec![], vec![]),
+            Scope::Except(parts) => (vec![], parts.clone()),
+            Scope::Listed(parts) => (parts.clone(), vec![]),
+        };
+        let entry = json!({
+            "label": label,
+            "kind": scope.kind(),
+            "departments": listed,
+            "excludes": excludes,
+            "covered": catalog::covered_programs(&departments, &college, &scope),
+        });
+
+        match colleges.iter_mut().find(|(name, _)| *name == college) {
+            Some((_, entries)) => entries.push(entry),
+            None => colleges.push((college, vec![entry])),
+        }
+    }
+
+    json!({
+        "as_of": as_of,
+        "college": filter,
+        "college_count": colleges.len(),
+        "colleges": colleges
+            .into_iter()
+            .map(|(college, rows)| json!({ "college": college, "rows": rows }))
+            .collect::<Vec<_>>(),
+        "skipped_rows": skipped_credit_rows(as_of),
+        "citation": "(학점 이수 체계.pdf, p.1)",
+        "catalog_citation": catalog::CATALOG_CITATION,
+        "notes": [
+            "kind=전체: 해당 대학 모든 학과(부)에 같은 기준 적용",
+            "kind=외: excludes에 나열된 학과(부)를 제외한 나머지 학과(부)에 적용",
+            "covered: 프로그램 카탈로그(학칙 별표 1-23 모집단위) 기준으로 해당 행이 적용되는 학과(부)/전공"
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn earned() -> CreditTotals {
+        CreditTotals {
+            liberal_required: 19,
+            liberal_elective: 9,
+            major_basic: 9,
+            major: 36,
+            total: 133,
+        }
+    }
+
+    fn secondary(college: &str, department: &str, program_type: &'static str, earned: i64) -> SecondaryProgram {
+        SecondaryProgram {
+            college: college.to_string(),
+            department: department.to_string(),
+            program_type,
+            earned,
+        }
+    }
+
+    #[test]
+    fn secondary_program_in_the_major_department_is_rejected() {
+        let secondaries = [
+            secondary("경영대", "경영학", "복수전공", 36),
+            secondary("자연과학대학", "수학과", "부전공", 21),
+        ];
+        let result = evaluate_programs("경영대학", "경영학부", &earned(), &secondaries, Some(2024), None);
+
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["primary"]["column"], "복수전공자(주전공)");
+        let programs = result["secondary_programs"].as_array().expect("programs");
+        assert_eq!(programs[0]["allowed"], false);
+        assert_eq!(programs[0]["program"]["department"], "경영학부");
+        assert!(programs[0]["reason"].as_str().unwrap_or_default().contains("같은 학과(부)"));
+        assert_eq!(programs[1]["allowed"], true);
+        assert_eq!(programs[1]["column"], "부전공자");
+        assert_eq!(programs[1]["gap"], 0);
+    }
+
+    #[test]
+    fn programs_list_the_departments_each_row_covers() {
+        let result = list_programs(Some("인문대"), None);
+        assert_eq!(result["college"], "인문대학");
+        assert_eq!(result["college_count"], 1);
+
+        let rows = result["colleges"][0]["rows"].as_array().expect("rows");
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1]["kind"], "명시");
+        assert_eq!(rows[1]["covered"], json!(["예술창작학부 영화예술전공", "스포츠학부"]));
+
+        let except = &rows[2];
+        assert_eq!(except["kind"], "외");
+        assert_eq!(except["excludes"], json!(["독어독문학과", "예술창작학부 영화예술전공", "스포츠학부"]));
+        let covered = except["covered"].as_array().expect("covered");
+        assert!(covered.contains(&json!("국어국문학과")));
+        assert!(covered.contains(&json!("예술창작학부 문예창작전공")));
+        assert!(!covered.contains(&json!("독어독문학과")));
+        assert!(!covered.contains(&json!("스포츠학부")));
+
+        assert_eq!(list_programs(None, None)["college_count"], 8);
+    }
+}
@@ -0,0 +1,232 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::date::CivilDate;
+use crate::{gaps, table, PATH_CALENDAR};
+
+static SECTION_YEAR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{4})년").expect("SECTION_YEAR_RE compile failure"));
+static PERIOD_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{2})-(\d{2})(?:\([^)]*\))?(?:\s*~\s*(\d{2})-(\d{2}))?")
+        .expect("PERIOD_RE compile failure")
+});
+static REFUND_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{4})학년도 (\d)학기 휴학 시 수업료 (전액 반환|(\d+)/(\d+) 차감) 기간")
+        .expect("REFUND_RE compile failure")
+});
+
+pub(crate) const REFUND_REASONS: &[&str] = &["휴학", "자퇴"];
+
+struct RefundWindow {
+    semester: String,
+    start: CivilDate,
+    end: CivilDate,
+    label: String,
+    deduction: (i64, i64),
+    period: String,
+    event: String,
+    citation: String,
+}
+
+impl RefundWindow {
+    fn summary(&self) -> Value {
+        json!({
+            "semester": self.semester,
+            "window": self.label,
+            "start": self.start.iso(),
+            "end": self.end.iso(),
+            "기간": self.period,
+            "일정": self.event,
+            "citation": self.citation,
+        })
+    }
+}
+
+fn refund_windows(as_of: Option<&str>) -> Vec<RefundWindow> {
+    let mut windows = Vec::new();
+    for table in table::extract_tables(PATH_CALENDAR, as_of) {
+        let Some(year) = SECTION_YEAR_RE
+            .captures(&table.section)
+            .and_then(|caps| caps[1].parse::<i64>().ok())
+        else {
+            continue;
+        };
+        for row in &table.rows {
+            let (Some(period), Some(event)) = (table.cell(row, "기간"), table.cell(row, "일정")) else {
+                continue;
+            };
+            let (Some(dates), Some(refund)) = (PERIOD_RE.captures(period), REFUND_RE.captures(event)) else {
+                continue;
+            };
+            let month = |index: usize| dates.get(index).and_then(|value| value.as_str().parse::<i64>().ok());
+            let (Some(start_month), Some(start_day)) = (month(1), month(2)) else {
+                continue;
+            };
+            let (end_month, end_day) = (month(3).unwrap_or(start_month), month(4).unwrap_or(start_day));
+            let end_year = if end_month < start_month { year + 1 } else { year };
+            let (Some(start), Some(end)) = (
+                CivilDate::parse(&format!("{year}-{start_month:02}-{start_day:02}")),
+                CivilDate::parse(&format!("{end_year}-{end_month:02}-{end_day:02}")),
+            ) else {
+                continue;
+            };
+            let deduction = match (refund.get(4), refund.get(5)) {
+                (Some(numerator), Some(denominator)) => (
+                    numerator.as_str().parse().unwrap_or(0),
+                    denominator.as_str().parse().unwrap_or(1),
+                ),
+                _ => (0, 1),
+            };
+            windows.push(RefundWindow {
+                semester: format!("{}학년도 {}학기", &refund[1], &refund[2]),
+                start,
+                end,
+                label: refund[3].to_string(),
+                deduction,
+                period: period.to_string(),
+                event: event.to_string(),
+                citation: table
+                    .page
+                    .as_deref()
+                    .map(|page| format!("(학사 일정.pdf, p.{page})"))
+                    .unwrap_or_else(|| "(학사 일정.pdf, 페이지 확인 필요)".to_string()),
+            });
+        }
+    }
+    windows.sort_by_key(|window| window.start);
+    windows
+}
+
+pub(crate) fn calculate_tuition_refund(date: CivilDate, tuition: i64, reason: &str, as_of: Option<&str>) -> Value {
+    let windows = refund_windows(as_of);
+    if windows.is_empty() {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "학사 일정.md에서 휴학 시 수업료 반환 기간을 찾지 못함",
+            "citation": "(학사 일정.pdf)"
+        });
+    }
+    let known_gap = gaps::gap_named("수업료 반환 기준(자퇴·기간 외)", as_of).map(|gap| gaps::gap_summary(&gap));
+    let mut notes = vec!["수업료·입학금의 반환 사유와 기준은 따로 규정으로 정함 (학칙.pdf, p.8, 제33조)".to_string()];
+
+    let Some(window) = windows.iter().find(|window| window.start <= date && date <= window.end) else {
+        let previous = windows.iter().rev().find(|window| window.end < date);
+        let next = windows.iter().find(|window| window.start > date);
+        let reason_text = match (previous, next) {
+            (Some(previous), Some(next)) if previous.semester != next.semester => format!(
+                "{} 반환 기간 종료({}) 후 {} {} 시작({}) 이전 날짜: 학사 일정에 두 학기 사이의 반환 기준이 없음",
+                previous.semester,
+                previous.end.iso(),
+                next.semester,
+                next.label,
+                next.start.iso()
+            ),
+            (_, Some(next)) if next.label == "전액 반환" => format!(
+                "{} {} 시작({}) 이전 날짜: 학사 일정에 이 날짜의 반환 기준이 없음",
+                next.semester,
+                next.label,
+                next.start.iso()
+            ),
+            (Some(previous), _) => format!(
+                "{} 마지막 반환 기간({} 종료) 이후 날짜: 학사 일정에 이후 반환 기간이 없음",
+                previous.semester,
+                previous.end.iso()
+            ),
+            _ => format!("{}은 학사 일정의 반환 기간 범위 밖", date.iso()),
+        };
+        if next.is_none() {
+            notes.push("학사 일정 스냅샷 이후 학기는 해당 학년도 학사 일정 필요".to_string());
+        }
+        return json!({
+            "judgement": "판정 불가",
+            "reason": reason_text,
+            "date": date.iso(),
+            "tuition": tuition,
+            "refund_reason": reason,
+            "previous_window": previous.map(RefundWindow::summary),
+            "next_window": next.map(RefundWindow::summary),
+            "known_gap": known_gap,
+            "citation": previous.or(next).map(|window| window.citation.clone()),
+            "notes": notes,
+        });
+    };
+
+    let (numerator, denominator) = window.deduction;
+    let deducted = tuition * numerator / denominator;
+    let refund = tuition - deducted;
+    let semester_windows: Vec<Value> = windows
+        .iter()
+        .filter(|candidate| candidate.semester == window.semester)
+        .map(RefundWindow::summary)
+        .collect();
+    notes.push(match numerator {
+        0 => "전액 반환 기간: 납부한 수업료 전액 반환".to_string(),
+        _ => format!("수업료 {numerator}/{denominator} 차감 후 나머지 반환(차감액 원 단위 미만 버림)"),
+    });
+    if reason == "자퇴" {
+        notes.push("학사 일정에는 휴학 시 반환 기간만 있어 자퇴에도 같은 기간을 적용한 추정치: 자퇴 반환 기준은 등록금 반환 규정 확인 필요".to_string());
+    }
+    notes.push("입학금과 기타 납입금은 계산에 포함하지 않음".to_string());
+
+    json!({
+        "judgement": if reason == "휴학" { "가능" } else { "판정 불가" },
+        "reason": format!("{} {} 기간({} ~ {})에 해당", window.semester, window.label, window.start.iso(), window.end.iso()),
+        "date": date.iso(),
+        "tuition": tuition,
+        "refund_reason": reason,
+        "window": window.summary(),
+        "deduction": if numerator == 0 { "없음".to_string() } else { format!("{numerator}/{denominator}") },
+        "deducted_amount": deducted,
+        "refund_amount": refund,
+        "estimated": reason != "휴학",
+        "semester_windows": semester_windows,
+        "known_gap": (reason != "휴학").then_some(known_gap).flatten(),
+        "citation": window.citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refund_on(date: &str, tuition: i64) -> Value {
+        calculate_tuition_refund(CivilDate::parse(date).expect("date"), tuition, "휴학", None)
+    }
+
+    #[test]
+    fn one_third_window_deducts_a_third() {
+        for date in ["2026-04-01", "2026-04-30"] {
+            let result = refund_on(date, 3_000_001);
+            assert_eq!(result["judgement"], "가능", "{date}");
+            assert_eq!(result["window"]["window"], "1/3 차감");
+            assert_eq!(result["deducted_amount"], 1_000_000);
+            assert_eq!(result["refund_amount"], 2_000_001);
+        }
+        assert_eq!(refund_on("2026-03-31", 600)["deduction"], "1/6");
+        assert_eq!(refund_on("2026-05-01", 600)["deduction"], "1/2");
+    }
+
+    #[test]
+    fn date_between_semesters_has_no_window() {
+        let result = refund_on("2026-07-15", 1_000_000);
+        assert_eq!(result["judgement"], "판정 불가");
+        assert_eq!(result["previous_window"]["semester"], "2026학년도 1학기");
+        assert_eq!(result["next_window"]["window"], "전액 반환");
+    }
+
+    #[test]
+    fn dates_outside_the_calendar_have_no_window() {
+        let before = refund_on("2020-01-01", 1_000_000);
+        assert_eq!(before["judgement"], "판정 불가");
+        assert!(before["previous_window"].is_null());
+        assert_eq!(before["next_window"]["window"], "전액 반환");
+        assert!(before["refund_amount"].is_null());
+
+        let after = refund_on("2030-01-01", 1_000_000);
+        assert_eq!(after["judgement"], "판정 불가");
+        assert!(after["next_window"].is_null());
+        assert!(after["notes"].to_string().contains("학사 일정 스냅샷 이후 학기"));
+    }
+}
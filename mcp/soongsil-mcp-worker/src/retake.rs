@@ -0,0 +1,275 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::transcript::course_key;
+use crate::{gaps, get_file_text, liberal, PATH_LIBERAL_REQUIRED};
+
+static COURSE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"`([^`]+)`").expect("COURSE_RE compile failure"));
+static UNTIL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{4})학년도까지").expect("UNTIL_RE compile failure"));
+static NUMBERED_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.*?)\s*(\d)/(\d)$").expect("NUMBERED_RE compile failure"));
+
+#[derive(Clone, Copy)]
+enum SectionStatus {
+    Until(i64),
+    EveryTerm,
+    Abolished,
+}
+
+struct RetakeEntry {
+    course: String,
+    status: SectionStatus,
+    rule: String,
+    citation: String,
+}
+
+fn course_variants(name: &str) -> Vec<String> {
+    match NUMBERED_RE.captures(name) {
+        Some(caps) => vec![
+            name.to_string(),
+            caps[1].to_string(),
+            format!("{} {}", &caps[1], &caps[2]),
+            format!("{} {}", &caps[1], &caps[3]),
+        ],
+        None => vec![name.to_string()],
+    }
+}
+
+fn retake_entries(as_of: Option<&str>) -> Vec<RetakeEntry> {
+    let text = get_file_text(PATH_LIBERAL_REQUIRED, as_of).unwrap_or_default();
+    let mut entries = Vec::new();
+    let mut page = None;
+    let mut in_section = false;
+
+    for line in text.lines() {
+        let stripped = line.trim();
+        if let Some(value) = stripped.strip_prefix("## p.") {
+            page = Some(value.trim().to_string());
+            in_section = false;
+            continue;
+        }
+        if stripped.starts_with('#') {
+            in_section = stripped.contains("재수강 분반");
+            continue;
+        }
+        let Some(item) = stripped.strip_prefix("- ").filter(|_| in_section) else {
+            continue;
+        };
+        let Some((courses, rule)) = item.split_once(':') else {
+            continue;
+        };
+        let rule = rule.trim().replace('`', "");
+        let status = if rule.contains("폐지") {
+            SectionStatus::Abolished
+        } else if rule.contains("매학기") {
+            SectionStatus::EveryTerm
+        } else if let Some(year) = UNTIL_RE.captures(&rule).and_then(|caps| caps[1].parse().ok()) {
+            SectionStatus::Until(year)
+        } else {
+            continue;
+        };
+        let citation = page
+            .as_deref()
+            .map(|page| format!("(교양 필수.pdf, p.{page})"))
+            .unwrap_or_else(|| "(교양 필수.pdf, 페이지 확인 필요)".to_string());
+        for caps in COURSE_RE.captures_iter(courses) {
+            entries.push(RetakeEntry {
+                course: caps[1].trim().to_string(),
+                status,
+                rule: rule.clone(),
+                citation: citation.clone(),
+            });
+        }
+    }
+
+    entries
+}
+
+fn current_course(key: &str, as_of: Option<&str>) -> Option<Value> {
+    let table = liberal::curriculum_table(as_of)?;
+    let row = table
+        .rows
+        .iter()
+        .find(|row| table.cell(row, "과목").map(course_key).as_deref() == Some(key))?;
+    let mut values = table.row_map(row);
+    values.insert("section".to_string(), json!(table.section));
+    values.insert("citation".to_string(), json!(table.citation()));
+    Some(Value::Object(values))
+}
+
+fn current_curriculum(as_of: Option<&str>) -> Value {
+    let Some(table) = liberal::curriculum_table(as_of) else {
+        return Value::Null;
+    };
+    json!({
+        "section": table.section,
+        "courses": table
+            .rows
+            .iter()
+            .filter_map(|row| table.cell(row, "과목"))
+            .collect::<Vec<_>>(),
+        "citation": table.citation(),
+    })
+}
+
+pub(crate) fn analyze_retake(course: &str, semester: &str, year: i64, term: &str, as_of: Option<&str>) -> Value {
+    let entries = retake_entries(as_of);
+    if entries.is_empty() {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "교양 필수.md에서 재수강 분반 운영 목록을 찾지 못함",
+            "citation": "(교양 필수.pdf, p.2)"
+        });
+    }
+
+    let key = course_key(course);
+    let entry = entries
+        .iter()
+        .find(|entry| course_variants(&entry.course).iter().any(|name| course_key(name) == key));
+    let same_name_course = current_course(&key, as_of);
+    let seasonal = matches!(term, "여름학기" | "겨울학기");
+    let limit_gap = gaps::gap_named("재수강 횟수/학점 한도", as_of).map(|gap| gaps::gap_summary(&gap));
+    let substitute_gap = gaps::gap_named("교양필수 재수강 대체과목", as_of).map(|gap| gaps::gap_summary(&gap));
+    let mut notes = Vec::new();
+
+    let Some(entry) = entry else {
+        let reason = if same_name_course.is_some() {
+            "2023학년도 이후 교양필수 교과과정에 편성된 과목: 정규 분반 수강 대상은 교과과정 표 참고, 별도 재수강 분반 운영 정보는 코퍼스에 없음"
+        } else {
+            "2022학년도 이전 교양필수 재수강 분반 목록과 현행 교양필수 교과과정에 없는 과목: 전공·교양선택 과목의 재수강 개설 여부는 코퍼스에 없음"
+        };
+        notes.push("재수강 분반 목록은 2022학년도 이전 교양필수 교과목만 다룸 (교양 필수.pdf, p.2)".to_string());
+        return json!({
+            "judgement": "판정 불가",
+            "reason": reason,
+            "course": course,
+            "semester": semester,
+            "retake_section": Value::Null,
+            "current_course": same_name_course,
+            "known_gap": limit_gap,
+            "citation": "(교양 필수.pdf, p.2)",
+            "notes": notes,
+        });
+    };
+
+    let (available, reason) = match entry.status {
+        SectionStatus::Abolished => (Some(false), format!("{}: 폐지 과목으로 재수강 분반 운영 없음", entry.course)),
+        SectionStatus::Until(until) if year > until => (
+            Some(false),
+            format!("{}: 재수강 분반은 {until}학년도까지만 운영되어 {year}학년도 {term}에는 없음", entry.course),
+        ),
+        SectionStatus::Until(until) if seasonal => (
+            None,
+            format!("{}: {until}학년도까지 재수강 분반 운영, 계절학기 개설 여부는 코퍼스에 없음", entry.course),
+        ),
+        SectionStatus::Until(until) => (
+            Some(true),
+            format!("{}: {until}학년도까지 재수강 분반 운영 ({year}학년도 {term} 포함)", entry.course),
+        ),
+        SectionStatus::EveryTerm if seasonal => (
+            None,
+            format!("{}: 매학기 재수강 분반 운영, 계절학기 개설 여부는 코퍼스에 없음", entry.course),
+        ),
+        SectionStatus::EveryTerm => (Some(true), format!("{}: 매학기 재수강 분반 운영", entry.course)),
+    };
+    let operated_until = match entry.status {
+        SectionStatus::Until(until) => json!(format!("{until}학년도")),
+        SectionStatus::EveryTerm => json!("매학기"),
+        SectionStatus::Abolished => json!("폐지"),
+    };
+
+    let substitute = (available == Some(false)).then(|| {
+        let status = if same_name_course.is_some() {
+            "같은 이름의 현행 교양필수 과목이 있으나 재수강으로 인정되는지는 코퍼스에 없음"
+        } else {
+            "대체 교과목 지정이 코퍼스에 없음: 현행 교양필수 과목 중 대체 인정 여부는 교양교육 운영 부서 확인 필요"
+        };
+        json!({
+            "course": Value::Null,
+            "status": status,
+            "same_name_course": same_name_course,
+            "current_curriculum": current_curriculum(as_of),
+            "known_gap": substitute_gap,
+        })
+    });
+
+    if available == Some(true) {
+        notes.push("재수강 분반 운영은 2022학년도 이전 교양필수 교과목을 이수한 학생의 재수강용 (교양 필수.pdf, p.2)".to_string());
+    }
+    notes.push("재수강 성적 처리(대체/모두 반영)에 따른 평점 변화는 ssu_calculate_gpa의 retake_policy로 확인".to_string());
+    if limit_gap.is_some() {
+        notes.push("재수강 횟수·학점 한도는 코퍼스에 근거 문서가 없음(source-map Known Gaps)".to_string());
+    }
+
+    json!({
+        "judgement": match available {
+            Some(true) => "가능",
+            Some(false) => "불가",
+            None => "판정 불가",
+        },
+        "reason": reason,
+        "course": entry.course,
+        "semester": semester,
+        "academic_year": year,
+        "term": term,
+        "retake_section": available,
+        "operated_until": operated_until,
+        "rule": entry.rule,
+        "substitute": substitute,
+        "current_course": (available != Some(false)).then_some(same_name_course).flatten(),
+        "known_gap": limit_gap,
+        "citation": entry.citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::parse_term;
+
+    fn retake(course: &str, semester: &str) -> Value {
+        let (year, term) = parse_term(semester).expect("term");
+        analyze_retake(course, semester, year, term, None)
+    }
+
+    #[test]
+    fn until_sections_close_after_their_last_year() {
+        let open = retake("기업가정신과행동", "2024-2");
+        assert_eq!(open["judgement"], "가능");
+        assert_eq!(open["operated_until"], "2024학년도");
+        assert!(open["substitute"].is_null());
+
+        let closed = retake("기업가정신과행동", "2025-1");
+        assert_eq!(closed["judgement"], "불가");
+        assert!(closed["substitute"]["status"].is_string());
+
+        let numbered = retake("Academic and Professional English 2", "2024-1");
+        assert_eq!(numbered["judgement"], "가능");
+        assert_eq!(numbered["course"], "Academic and Professional English 1/2");
+    }
+
+    #[test]
+    fn abolished_sections_are_closed_in_any_year() {
+        let result = retake("데이터와사회", "2020-1");
+        assert_eq!(result["judgement"], "불가");
+        assert_eq!(result["operated_until"], "폐지");
+        assert_eq!(result["retake_section"], false);
+    }
+
+    #[test]
+    fn seasonal_terms_are_undecided_while_sections_run() {
+        let summer = retake("독서와토론", "2023-여름");
+        assert_eq!(summer["judgement"], "판정 불가");
+        assert_eq!(summer["term"], "여름학기");
+        assert!(summer["retake_section"].is_null());
+
+        let winter = retake("한반도평화와통일", "2026-겨울");
+        assert_eq!(winter["judgement"], "판정 불가");
+        assert_eq!(retake("한반도평화와통일", "2026-2")["judgement"], "가능");
+
+        assert_eq!(retake("독서와토론", "2024-여름")["judgement"], "불가");
+    }
+}
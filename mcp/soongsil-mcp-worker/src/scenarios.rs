@@ -0,0 +1,270 @@
+use serde_json::{json, Map, Value};
+
+use crate::{
+    catalog, evaluate_credit_totals, extract_first_int, load_credit_rows, match_credit_row, numeric_rule,
+    optional_string, required_string, row_string, CreditTotals, MAJOR_TYPES,
+};
+
+pub(crate) struct Target {
+    pub college: String,
+    pub department: String,
+}
+
+pub(crate) fn parse_alternatives(args: &Map<String, Value>) -> std::result::Result<Vec<Target>, String> {
+    let Some(items) = args.get("alternatives") else {
+        return Ok(vec![]);
+    };
+    let items = items.as_array().ok_or("'alternatives' must be an array")?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("alternatives[{index}] must be an object"))?;
+            Ok(Target {
+                college: required_string(object, "college")
+                    .map_err(|message| format!("alternatives[{index}]: {message}"))?
+                    .trim()
+                    .to_string(),
+                department: optional_string(object, "department")
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn major_types_filter(args: &Map<String, Value>) -> std::result::Result<Vec<&'static str>, String> {
+    let Some(items) = args.get("major_types") else {
+        return Ok(MAJOR_TYPES.to_vec());
+    };
+    let items = items.as_array().ok_or("'major_types' must be an array")?;
+    items
+        .iter()
+        .map(|item| {
+            let raw = item.as_str().unwrap_or_default();
+            MAJOR_TYPES
+                .iter()
+                .find(|major_type| **major_type == raw.trim())
+                .copied()
+                .ok_or_else(|| format!("'major_types' entries must be one of {}", MAJOR_TYPES.join("/")))
+        })
+        .collect()
+}
+
+fn column_meaning(major_type: &str) -> &'static str {
+    match major_type {
+        "단일전공자" => "이 학과(부)만 전공(부전공 병행 시 주전공 포함)",
+        "부전공자" => "이 학과(부)를 부전공으로 이수",
+        "복수전공자(주전공)" => "복수전공을 병행할 때 이 학과(부) 주전공",
+        _ => "이 학과(부)를 복수전공으로 이수",
+    }
+}
+
+fn is_primary_column(major_type: &str) -> bool {
+    matches!(major_type, "단일전공자" | "복수전공자(주전공)")
+}
+
+fn remaining_credits(gap: &Value) -> Option<i64> {
+    let gap = gap.as_object()?;
+    let total = gap.get("졸업학점").and_then(Value::as_i64).unwrap_or(0);
+    let categories: i64 = gap
+        .iter()
+        .filter(|(key, _)| key.as_str() != "졸업학점")
+        .filter_map(|(_, value)| value.as_i64())
+        .sum();
+    Some(total.max(categories))
+}
+
+pub(crate) fn compare_scenarios(
+    current: &Target,
+    alternatives: &[Target],
+    major_types: &[&str],
+    earned: &CreditTotals,
+    admission_year: Option<i64>,
+    as_of: Option<&str>,
+) -> Value {
+    let rows = load_credit_rows(as_of);
+    let per_semester = numeric_rule("학사 연간 이수학점", as_of)
+        .and_then(|(value, _)| extract_first_int(&value))
+        .map(|cap| (cap / 2).max(1));
+    let current_program = catalog::resolve(&current.college, &current.department, as_of);
+
+    let mut scenarios = Vec::new();
+    let mut table = Vec::new();
+    let mut blocked = Vec::new();
+    let mut skipped = Vec::new();
+    let targets = std::iter::once(("현재", current)).chain(alternatives.iter().map(|target| ("전과", target)));
+    for (kind, target) in targets {
+        let program = catalog::resolve(&target.college, &target.department, as_of);
+        if kind == "전과" && program.known && program.department == current_program.department {
+            skipped.push(format!("{}: 현재 소속과 같은 학과(부)라 전과 시나리오에서 제외", program.label()));
+            continue;
+        }
+        let matched = match_credit_row(&rows, &program);
+        let mut cells = Map::new();
+        for major_type in major_types {
+            let label = format!("{kind} {} · {major_type}", program.label());
+            let blocked_row = matched
+                .as_ref()
+                .map(|matched| &matched.row)
+                .filter(|row| row_string(row, major_type).trim() == "불허");
+            if let Some(row) = blocked_row {
+                blocked.push(format!(
+                    "{label}: {} {}의 {major_type} 칸이 불허 (학점 이수 체계.pdf, p.1)",
+                    row_string(row, "대학"),
+                    row_string(row, "학과/학부")
+                ));
+                cells.insert(major_type.to_string(), json!("불허"));
+                scenarios.push(json!({
+                    "scenario": label,
+                    "kind": kind,
+                    "college": program.college,
+                    "department": program.label(),
+                    "major_type": major_type,
+                    "meaning": column_meaning(major_type),
+                    "allowed": false,
+                    "judgement": "불가",
+                    "matched_rule": row.values,
+                    "citation": "(학점 이수 체계.pdf, p.1)",
+                }));
+                continue;
+            }
+
+            let result = evaluate_credit_totals(
+                &target.college,
+                &target.department,
+                major_type,
+                earned,
+                admission_year,
+                as_of,
+            );
+            let remaining = remaining_credits(&result["gap"]);
+            cells.insert(major_type.to_string(), json!(remaining));
+            scenarios.push(json!({
+                "scenario": label,
+                "kind": kind,
+                "college": program.college,
+                "department": program.label(),
+                "major_type": major_type,
+                "meaning": column_meaning(major_type),
+                "allowed": remaining.map(|_| true),
+                "judgement": result["judgement"].clone(),
+                "required": result["required"].clone(),
+                "gap": result["gap"].clone(),
+                "remaining_credits": remaining,
+                "estimated_semesters": remaining.zip(per_semester).map(|(remaining, per)| (remaining + per - 1) / per),
+                "unverified": result["unverified"].clone(),
+                "row_match": result["row_match"].clone(),
+                "citation": result["citation"].clone(),
+            }));
+        }
+        table.push(json!({
+            "kind": kind,
+            "college": program.college,
+            "department": program.label(),
+            "row": matched.as_ref().map(|matched| row_string(&matched.row, "학과/학부")),
+            "remaining_credits": cells,
+        }));
+    }
+
+    let primary: Vec<&Value> = scenarios
+        .iter()
+        .filter(|scenario| is_primary_column(scenario["major_type"].as_str().unwrap_or_default()))
+        .collect();
+    let fewest = primary
+        .iter()
+        .filter_map(|scenario| scenario["remaining_credits"].as_i64())
+        .min();
+    let fastest: Vec<&Value> = primary
+        .iter()
+        .filter(|scenario| fewest.is_some() && scenario["remaining_credits"].as_i64() == fewest)
+        .map(|scenario| &scenario["scenario"])
+        .collect();
+
+    let mut notes = vec![
+        "remaining_credits는 졸업학점 부족분과 이수구분별 부족분 합계 중 큰 값".to_string(),
+        "fastest는 주전공 열(단일전공자, 복수전공자(주전공))만 비교: 부전공자/복수전공자(복수전공) 열은 그 학과(부)를 다전공으로 이수할 때의 기준".to_string(),
+        "같은 earned 학점을 모든 시나리오에 그대로 적용: 전과 시 기존 전공 학점 인정 범위는 제50조와 시행세칙 확인 필요 (학칙.pdf, p.12, 제50조)".to_string(),
+        "다전공 학과(부) 쪽 이수학점까지 함께 보려면 ssu_evaluate_multi_major 사용".to_string(),
+    ];
+    if let Some(per) = per_semester {
+        notes.push(format!("estimated_semesters는 학기당 {per}학점(연간 한도의 절반) 기준 추정"));
+    }
+    if !alternatives.is_empty() {
+        notes.push("전과 자격·시기는 제30조 소관 (학칙.pdf, p.7, 제30조)".to_string());
+    }
+    notes.extend(skipped);
+
+    json!({
+        "current": current_program.summary(),
+        "major_types": major_types,
+        "table": table,
+        "scenarios": scenarios,
+        "fastest": fastest,
+        "blocked": blocked,
+        "citation": "(학점 이수 체계.pdf, p.1)",
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARNED: CreditTotals = CreditTotals {
+        liberal_required: 19,
+        liberal_elective: 9,
+        major_basic: 9,
+        major: 60,
+        total: 120,
+    };
+
+    fn target(college: &str, department: &str) -> Target {
+        Target {
+            college: college.to_string(),
+            department: department.to_string(),
+        }
+    }
+
+    #[test]
+    fn fastest_lists_every_tied_scenario() {
+        let current = target("IT대학", "컴퓨터학부");
+        let alternatives = [target("IT대학", "소프트웨어학부")];
+        let result = compare_scenarios(&current, &alternatives, &["단일전공자"], &EARNED, Some(2024), None);
+        assert_eq!(result["scenarios"][0]["remaining_credits"], 18);
+        assert_eq!(result["scenarios"][1]["remaining_credits"], 18);
+        assert_eq!(
+            result["fastest"],
+            json!(["현재 컴퓨터학부 · 단일전공자", "전과 소프트웨어학부 · 단일전공자"])
+        );
+    }
+
+    #[test]
+    fn fastest_ignores_blocked_and_secondary_columns() {
+        let current = target("IT대학", "컴퓨터학부");
+        let alternatives = [target("IT대학", "소프트웨어학부"), target("경영대학", "금융학부")];
+        let result = compare_scenarios(
+            &current,
+            &alternatives,
+            &["단일전공자", "부전공자"],
+            &EARNED,
+            Some(2024),
+            None,
+        );
+        assert_eq!(result["fastest"], json!(["전과 금융학부 · 단일전공자"]));
+        assert_eq!(result["blocked"].as_array().map(Vec::len), Some(2));
+        assert_eq!(result["table"][0]["remaining_credits"]["부전공자"], "불허");
+    }
+
+    #[test]
+    fn same_department_alternatives_are_skipped() {
+        let current = target("IT대학", "컴퓨터학부");
+        let alternatives = [target("IT대", "컴공")];
+        let result = compare_scenarios(&current, &alternatives, &["단일전공자"], &EARNED, Some(2024), None);
+        assert_eq!(result["scenarios"].as_array().map(Vec::len), Some(1));
+        assert_eq!(result["fastest"], json!(["현재 컴퓨터학부 · 단일전공자"]));
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::date::{self, CivilDate};
+use crate::front_matter::parse_front_matter;
+use crate::hangul::compose_hangul;
+use crate::{
+    get_file_text, resolve_snapshot, PATH_CALENDAR, PATH_CREDIT_SYSTEM, PATH_HAKCHIK,
+    PATH_HAKCHIK_RAW, PATH_LIBERAL_ELECTIVE, PATH_LIBERAL_REQUIRED, PATH_SOURCE_MAP,
+};
+
+const DOCUMENT_PATHS: &[&str] = &[
+    PATH_HAKCHIK,
+    PATH_CREDIT_SYSTEM,
+    PATH_LIBERAL_REQUIRED,
+    PATH_LIBERAL_ELECTIVE,
+    PATH_CALENDAR,
+    PATH_HAKCHIK_RAW,
+];
+
+static PAGE_REF_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"p\.(\d+)").expect("PAGE_REF_RE compile failure"));
+static URL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"https?://\S+").expect("URL_RE compile failure"));
+
+struct SourceMapEntry {
+    pdf: String,
+    purpose: String,
+    notes: String,
+    reverify_after_days: Option<i64>,
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn page_count(text: &str, fields: &BTreeMap<String, String>) -> Option<i64> {
+    if let Some(total) = fields.get("total_pages").and_then(|v| v.parse::<i64>().ok()) {
+        return Some(total);
+    }
+    PAGE_REF_RE
+        .captures_iter(text)
+        .filter_map(|captures| captures[1].parse::<i64>().ok())
+        .max()
+}
+
+fn source_url(text: &str) -> Option<String> {
+    let mut in_link_section = false;
+    for line in text.lines() {
+        let stripped = line.trim();
+        if stripped.starts_with('#') {
+            in_link_section = stripped.contains("원문 링크");
+            continue;
+        }
+        if in_link_section || stripped.contains("원문 링크") {
+            if let Some(found) = URL_RE.find(stripped) {
+                return Some(found.as_str().trim_end_matches('`').to_string());
+            }
+        }
+    }
+    None
+}
+
+fn source_map_entries(text: &str) -> Vec<SourceMapEntry> {
+    let mut entries = Vec::new();
+    let mut in_documents = false;
+    for line in text.lines() {
+        let stripped = line.trim();
+        if stripped.starts_with("## ") {
+            in_documents = stripped == "## Documents";
+            continue;
+        }
+        if !in_documents || !stripped.starts_with('|') {
+            continue;
+        }
+        let cells = crate::split_pipe_row(stripped);
+        if cells.len() < 3 || cells[0] == "File" || cells[0].starts_with("---") {
+            continue;
+        }
+        entries.push(SourceMapEntry {
+            pdf: compose_hangul(file_name(cells[0].trim_matches('`'))),
+            purpose: cells[1].clone(),
+            notes: cells[2].clone(),
+            reverify_after_days: cells.get(3).and_then(|cell| cell.parse().ok()),
+        });
+    }
+    entries
+}
+
+fn source_map(as_of: Option<&str>) -> Vec<SourceMapEntry> {
+    source_map_entries(get_file_text(PATH_SOURCE_MAP, as_of).unwrap_or_default())
+}
+
+fn source_pdf(fields: &BTreeMap<String, String>) -> String {
+    fields
+        .get("source_pdf")
+        .map(|value| file_name(value).to_string())
+        .unwrap_or_default()
+}
+
+pub(crate) fn source_pdfs(as_of: Option<&str>) -> Vec<String> {
+    let mut pdfs: Vec<String> = Vec::new();
+    for path in DOCUMENT_PATHS {
+        let pdf = source_pdf(&parse_front_matter(get_file_text(path, as_of).unwrap_or_default()));
+        if !pdf.is_empty() && !pdfs.contains(&pdf) {
+            pdfs.push(pdf);
+        }
+    }
+    pdfs
+}
+
+fn reference_date(as_of: Option<&str>) -> CivilDate {
+    as_of.and_then(CivilDate::parse).unwrap_or_else(date::today)
+}
+
+fn verification_age_days(fields: &BTreeMap<String, String>, as_of: Option<&str>) -> Option<i64> {
+    let verified = fields
+        .get("last_verified_against_pdf")
+        .and_then(|value| CivilDate::parse_iso(value))?;
+    Some(verified.days_until(reference_date(as_of)))
+}
+
+pub(crate) fn staleness_warning(path: &str, as_of: Option<&str>) -> Option<String> {
+    let text = get_file_text(path, as_of)?;
+    let fields = parse_front_matter(text);
+    let pdf = source_pdf(&fields);
+    let limit = source_map(as_of)
+        .iter()
+        .find(|entry| entry.pdf == pdf)
+        .and_then(|entry| entry.reverify_after_days)?;
+    let age = verification_age_days(&fields, as_of)?;
+    if age <= limit {
+        return None;
+    }
+    Some(format!(
+        "{}: PDF 대조 검증 후 {age}일 경과(last_verified_against_pdf {}, 재확인 주기 {limit}일), 최신 원문 재확인 필요",
+        file_name(path),
+        fields.get("last_verified_against_pdf").cloned().unwrap_or_default()
+    ))
+}
+
+pub(crate) fn list_documents(as_of: Option<&str>) -> Vec<Value> {
+    let source_map = source_map(as_of);
+
+    DOCUMENT_PATHS
+        .iter()
+        .filter_map(|path| {
+            let snapshot = resolve_snapshot(path, as_of)?;
+            let fields = parse_front_matter(snapshot.text);
+            let pdf = source_pdf(&fields);
+            let entry = source_map.iter().find(|entry| entry.pdf == pdf);
+            let age = verification_age_days(&fields, as_of);
+            let limit = entry.and_then(|e| e.reverify_after_days);
+
+            Some(json!({
+                "file": path,
+                "pdf": pdf,
+                "snapshot": snapshot.effective_from,
+                "purpose": entry.map(|e| e.purpose.clone()),
+                "notes": entry.map(|e| e.notes.clone()),
+                "source_url": source_url(snapshot.text),
+                "page_count": page_count(snapshot.text, &fields),
+                "normalized_from_raw_md": fields.get("normalized_from_raw_md"),
+                "normalized_at": fields
+                    .get("normalized_at")
+                    .or_else(|| fields.get("table_normalized_at")),
+                "last_verified_against_pdf": fields.get("last_verified_against_pdf"),
+                "generated_at_utc": fields.get("generated_at_utc"),
+                "verification_age_days": age,
+                "reverify_after_days": limit,
+                "stale": age.zip(limit).map(|(days, limit)| days > limit),
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staleness_uses_the_source_map_period() {
+        assert!(staleness_warning(PATH_HAKCHIK, Some("2027-02-16")).is_none());
+        let warning = staleness_warning(PATH_HAKCHIK, Some("2027-02-17")).expect("stale after 365 days");
+        assert!(warning.contains("366일 경과"));
+        assert!(warning.contains("재확인 주기 365일"));
+
+        assert!(staleness_warning(PATH_CALENDAR, Some("2026-08-15")).is_none());
+        assert!(staleness_warning(PATH_CALENDAR, Some("2026-08-16")).is_some());
+    }
+
+    #[test]
+    fn documents_report_their_source_pdf_once() {
+        assert_eq!(
+            source_pdfs(None),
+            vec!["학칙.pdf", "학점 이수 체계.pdf", "교양 필수.pdf", "교양 선택.pdf", "학사 일정.pdf"]
+        );
+        let calendar = list_documents(Some("2026-10-18"))
+            .into_iter()
+            .find(|document| document["file"] == PATH_CALENDAR)
+            .expect("calendar");
+        assert_eq!(calendar["reverify_after_days"], 180);
+        assert_eq!(calendar["stale"], true);
+    }
+}
@@ -0,0 +1,287 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::catalog::{self, name_key, Program};
+use crate::table;
+use crate::{article_citation, PATH_LAW_NUMERIC};
+
+static LEVEL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d)학년[^(]*(?:\(([^)]*)\))?").expect("LEVEL_RE compile failure")
+});
+static THRESHOLD_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"누계\s*(\d+)학점 이상\s*\+\s*(\d+)학기").expect("THRESHOLD_RE compile failure")
+});
+static BEFORE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d{4})학년도 이전").expect("BEFORE_RE compile failure"));
+static COHORT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\d{4})(?:~(\d{4}))?학년도 입학자").expect("COHORT_RE compile failure")
+});
+
+pub(crate) struct StandingRule {
+    pub year: i64,
+    pub credits: i64,
+    pub semesters: i64,
+    pub programs: Vec<String>,
+    pub cohort: Option<(i64, i64)>,
+    pub condition: Option<String>,
+    pub citation: String,
+}
+
+impl StandingRule {
+    fn program_matches(&self, program: &Program) -> bool {
+        let label = name_key(&program.label());
+        self.programs.iter().all(|name| label.contains(&name_key(name)))
+    }
+
+    fn cohort_matches(&self, admission_year: Option<i64>) -> bool {
+        match (self.cohort, admission_year) {
+            (None, _) => true,
+            (Some((start, end)), Some(year)) => (start..=end).contains(&year),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> (Vec<String>, Option<(i64, i64)>) {
+    let mut programs = Vec::new();
+    let mut cohort = None;
+    for piece in condition.split(',').map(str::trim).filter(|piece| !piece.is_empty()) {
+        if let Some(caps) = BEFORE_RE.captures(piece) {
+            cohort = caps[1].parse::<i64>().ok().map(|year| (i64::MIN, year));
+        } else if let Some(caps) = COHORT_RE.captures(piece) {
+            let start = caps[1].parse::<i64>().unwrap_or_default();
+            let end = caps
+                .get(2)
+                .and_then(|end| end.as_str().parse::<i64>().ok())
+                .unwrap_or(start);
+            cohort = Some((start, end));
+        } else {
+            programs.push(piece.to_string());
+        }
+    }
+    (programs, cohort)
+}
+
+pub(crate) fn standing_rules(as_of: Option<&str>) -> Vec<StandingRule> {
+    let Some(table) = table::extract_tables(PATH_LAW_NUMERIC, as_of)
+        .into_iter()
+        .find(|table| table.section == "Credits / Progression")
+    else {
+        return vec![];
+    };
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let label = table.cell(row, "항목")?;
+            let level = LEVEL_RE.captures(label)?;
+            let threshold = THRESHOLD_RE.captures(table.cell(row, "수치")?)?;
+            let condition = level.get(2).map(|condition| condition.as_str().to_string());
+            let (programs, cohort) = condition.as_deref().map(parse_condition).unwrap_or_default();
+            Some(StandingRule {
+                year: level[1].parse().ok()?,
+                credits: threshold[1].parse().ok()?,
+                semesters: threshold[2].parse().ok()?,
+                programs,
+                cohort,
+                condition,
+                citation: article_citation(table.cell(row, "근거").unwrap_or_default()),
+            })
+        })
+        .collect()
+}
+
+fn applicable_rule<'a>(
+    rules: &'a [StandingRule],
+    year: i64,
+    program: &Program,
+    admission_year: Option<i64>,
+) -> Option<&'a StandingRule> {
+    rules
+        .iter()
+        .filter(|rule| rule.year == year && rule.program_matches(program) && rule.cohort_matches(admission_year))
+        .max_by_key(|rule| rule.programs.len() + usize::from(rule.cohort.is_some()))
+}
+
+fn is_five_year(rules: &[StandingRule], program: &Program, as_of: Option<&str>) -> Option<bool> {
+    let five_year_programs: Vec<&StandingRule> = rules
+        .iter()
+        .filter(|rule| rule.year == 5 && rule.cohort.is_none())
+        .collect();
+    if five_year_programs.iter().any(|rule| rule.program_matches(program)) {
+        return Some(true);
+    }
+    let undecided = program.major.is_none()
+        && catalog::departments(as_of)
+            .iter()
+            .filter(|department| department.name == program.department)
+            .flat_map(|department| department.majors.iter())
+            .any(|major| {
+                let label = name_key(&format!("{} {major}", program.department));
+                five_year_programs
+                    .iter()
+                    .any(|rule| rule.programs.iter().all(|name| label.contains(&name_key(name))))
+            });
+    if undecided {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+pub(crate) fn five_year_program(program: &Program, as_of: Option<&str>) -> Option<bool> {
+    is_five_year(&standing_rules(as_of), program, as_of)
+}
+
+pub(crate) fn evaluate_standing(
+    credits: i64,
+    semesters: i64,
+    college: &str,
+    department: &str,
+    admission_year: Option<i64>,
+    as_of: Option<&str>,
+) -> Value {
+    let rules = standing_rules(as_of);
+    if rules.is_empty() {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 학년 인정 기준 표를 찾지 못함",
+            "citation": "(학칙.pdf, p.10, 제43조 2항)"
+        });
+    }
+    let program = catalog::resolve(college, department, as_of);
+    let five_year = is_five_year(&rules, &program, as_of);
+    let program_years = if five_year == Some(true) { 5 } else { 4 };
+
+    let mut levels = Vec::new();
+    let mut recognized = 0;
+    let mut recognized_citation = None;
+    let mut next = Value::Null;
+    for year in 1..=program_years {
+        let Some(rule) = applicable_rule(&rules, year, &program, admission_year) else {
+            continue;
+        };
+        let met = credits >= rule.credits && semesters >= rule.semesters;
+        if met && recognized == year - 1 {
+            recognized = year;
+            recognized_citation = Some(rule.citation.clone());
+        } else if next.is_null() {
+            let credit_shortfall = (rule.credits - credits).max(0);
+            let semester_shortfall = (rule.semesters - semesters).max(0);
+            next = json!({
+                "year": year,
+                "required_credits": rule.credits,
+                "required_semesters": rule.semesters,
+                "credit_shortfall": credit_shortfall,
+                "semester_shortfall": semester_shortfall,
+                "limited_by": match (credit_shortfall > 0, semester_shortfall > 0) {
+                    (true, true) => "학점·등록학기",
+                    (true, false) => "학점",
+                    _ => "등록학기",
+                },
+                "citation": rule.citation,
+            });
+        }
+        levels.push(json!({
+            "year": year,
+            "required_credits": rule.credits,
+            "required_semesters": rule.semesters,
+            "condition": rule.condition,
+            "met": met,
+            "citation": rule.citation,
+        }));
+    }
+
+    let mut notes = vec![
+        "학년의 이수는 등록 학기 수와 누계 이수학점을 모두 충족해야 인정 (학칙.pdf, p.10, 제43조 2항)".to_string(),
+        "등록 학기에는 휴학 학기를 포함하지 않음".to_string(),
+    ];
+    match five_year {
+        Some(true) => notes.push("5년제(건축학부 건축학전공): 5학년까지 인정 기준 적용 (학칙.pdf, p.4, 제16조)".to_string()),
+        None => notes.push(format!(
+            "{}는 전공에 따라 5년제(건축학전공) 여부가 달라 4년제 기준으로 계산: department에 전공 입력 필요",
+            program.department
+        )),
+        Some(false) => {}
+    }
+    if admission_year.is_none() {
+        notes.push("admission_year 미입력: 2006학년도 이전 입학자와 소프트웨어 전공 한시 기준(142학점)은 적용하지 않음".to_string());
+    }
+    notes.push("학년별 수강신청 자격·학점 한도의 세부사항은 시행세칙 소관으로 코퍼스에 없음".to_string());
+
+    json!({
+        "cumulative_credits": credits,
+        "registered_semesters": semesters,
+        "admission_year": admission_year,
+        "program": program.summary(),
+        "five_year_program": five_year,
+        "program_years": program_years,
+        "recognized_year": recognized,
+        "standing": if recognized == 0 {
+            "1학년 이수 미인정".to_string()
+        } else {
+            format!("{recognized}학년 이수 인정")
+        },
+        "current_year": (recognized + 1).min(program_years),
+        "next": next,
+        "levels": levels,
+        "citation": recognized_citation.unwrap_or_else(|| "(학칙.pdf, p.10, 제43조 2항)".to_string()),
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn architecture_major_is_recognized_through_year_five() {
+        let result = evaluate_standing(163, 10, "공과대학", "건축학부 건축학전공", Some(2024), None);
+        assert_eq!(result["five_year_program"], true);
+        assert_eq!(result["program_years"], 5);
+        assert_eq!(result["recognized_year"], 5);
+        assert_eq!(result["standing"], "5학년 이수 인정");
+        assert_eq!(result["levels"][4]["required_credits"], 163);
+        assert_eq!(result["citation"], "(학칙.pdf, p.10, 제43조 2항 5호)");
+
+        let short = evaluate_standing(163, 9, "공과대학", "건축학전공", Some(2024), None);
+        assert_eq!(short["recognized_year"], 4);
+        assert_eq!(short["current_year"], 5);
+        assert_eq!(short["next"]["year"], 5);
+        assert_eq!(short["next"]["limited_by"], "등록학기");
+    }
+
+    #[test]
+    fn architecture_entrants_before_2006_use_the_lower_thresholds() {
+        let result = evaluate_standing(160, 10, "공과대학", "건축학부 건축학전공", Some(2005), None);
+        assert_eq!(result["recognized_year"], 5);
+        assert_eq!(result["levels"][3]["required_credits"], 130);
+        assert_eq!(result["levels"][4]["required_credits"], 160);
+        assert_eq!(
+            evaluate_standing(160, 10, "공과대학", "건축학부 건축학전공", Some(2006), None)["recognized_year"],
+            5
+        );
+        let later = evaluate_standing(160, 10, "공과대학", "건축학부 건축학전공", Some(2007), None);
+        assert_eq!(later["recognized_year"], 4);
+        assert_eq!(later["next"]["required_credits"], 163);
+    }
+
+    #[test]
+    fn other_architecture_majors_stay_four_year() {
+        let engineering = evaluate_standing(163, 10, "공과대학", "건축학부 건축공학전공", Some(2024), None);
+        assert_eq!(engineering["five_year_program"], false);
+        assert_eq!(engineering["program_years"], 4);
+        assert_eq!(engineering["recognized_year"], 4);
+        assert_eq!(engineering["current_year"], 4);
+
+        let undecided = evaluate_standing(163, 10, "공과대학", "건축학부", Some(2024), None);
+        assert_eq!(undecided["five_year_program"], Value::Null);
+        assert_eq!(undecided["program_years"], 4);
+        assert!(undecided["notes"]
+            .as_array()
+            .expect("notes")
+            .iter()
+            .any(|note| note.as_str().unwrap_or_default().contains("department에 전공 입력 필요")));
+    }
+}
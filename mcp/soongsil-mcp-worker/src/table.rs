@@ -0,0 +1,348 @@
+use serde_json::{json, Map, Value};
+
+use crate::{citation_hint, parse_page_heading, resolve_snapshot, split_pipe_row, SNAPSHOTS};
+
+#[derive(Clone)]
+pub(crate) struct TableRow {
+    pub line: usize,
+    pub cells: Vec<String>,
+}
+
+#[derive(Clone)]
+pub(crate) struct CorpusTable {
+    pub file: &'static str,
+    pub line: usize,
+    pub page: Option<String>,
+    pub section: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<TableRow>,
+}
+
+impl CorpusTable {
+    pub(crate) fn column(&self, name: &str) -> Option<usize> {
+        self.headers.iter().position(|header| header == name)
+    }
+
+    pub(crate) fn has_columns(&self, names: &[&str]) -> bool {
+        names.iter().all(|name| self.column(name).is_some())
+    }
+
+    pub(crate) fn cell<'a>(&self, row: &'a TableRow, name: &str) -> Option<&'a str> {
+        self.column(name)
+            .and_then(|idx| row.cells.get(idx))
+            .map(String::as_str)
+    }
+
+    pub(crate) fn is_ragged(&self, row: &TableRow) -> bool {
+        row.cells.len() != self.headers.len()
+    }
+
+    pub(crate) fn skipped_rows(&self) -> Vec<Value> {
+        self.rows
+            .iter()
+            .filter(|row| self.is_ragged(row))
+            .map(|row| {
+                json!({
+                    "line": row.line,
+                    "cells": row.cells,
+                    "expected_columns": self.headers.len(),
+                    "citation": self.citation(),
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn citation(&self) -> String {
+        citation_hint(self.file, self.page.as_deref())
+    }
+
+    pub(crate) fn row_map(&self, row: &TableRow) -> Map<String, Value> {
+        let mut values = Map::new();
+        for (header, value) in self.headers.iter().zip(row.cells.iter()) {
+            values.insert(header.clone(), Value::String(value.clone()));
+        }
+        values
+    }
+
+    pub(crate) fn typed_row(&self, row: &TableRow) -> Map<String, Value> {
+        let mut values = Map::new();
+        for (header, value) in self.headers.iter().zip(row.cells.iter()) {
+            values.insert(header.clone(), typed_cell(value));
+        }
+        values
+    }
+}
+
+fn is_separator_row(line: &str) -> bool {
+    let cells = split_pipe_row(line);
+    !cells.is_empty()
+        && cells
+            .iter()
+            .all(|cell| !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':')))
+}
+
+pub(crate) fn typed_cell(value: &str) -> Value {
+    let trimmed = value.trim();
+    if trimmed.is_empty() || trimmed == "-" {
+        return Value::Null;
+    }
+    if let Ok(number) = trimmed.parse::<i64>() {
+        return json!(number);
+    }
+    if let Ok(number) = trimmed.parse::<f64>() {
+        return json!(number);
+    }
+    Value::String(trimmed.to_string())
+}
+
+pub(crate) fn extract_tables_from_text(file: &'static str, text: &str) -> Vec<CorpusTable> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut tables = Vec::new();
+    let mut page: Option<String> = None;
+    let mut section = String::new();
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let stripped = lines[idx].trim();
+        if let Some(p) = parse_page_heading(stripped) {
+            page = Some(p);
+            section.clear();
+            idx += 1;
+            continue;
+        }
+        if stripped.starts_with('#') {
+            section = stripped.trim_start_matches('#').trim().to_string();
+            idx += 1;
+            continue;
+        }
+
+        let has_separator = lines
+            .get(idx + 1)
+            .map(|next| next.trim().starts_with('|') && is_separator_row(next))
+            .unwrap_or(false);
+        if !stripped.starts_with('|') || !has_separator {
+            idx += 1;
+            continue;
+        }
+
+        let header_line = idx + 1;
+        let headers = split_pipe_row(stripped);
+        let mut rows = Vec::new();
+        idx += 2;
+        while idx < lines.len() && lines[idx].trim().starts_with('|') {
+            rows.push(TableRow {
+                line: idx + 1,
+                cells: split_pipe_row(lines[idx]),
+            });
+            idx += 1;
+        }
+
+        tables.push(CorpusTable {
+            file,
+            line: header_line,
+            page: page.clone(),
+            section: section.clone(),
+            headers,
+            rows,
+        });
+    }
+
+    tables
+}
+
+pub(crate) fn extract_tables(path: &str, as_of: Option<&str>) -> Vec<CorpusTable> {
+    resolve_snapshot(path, as_of)
+        .map(|snapshot| extract_tables_from_text(snapshot.path, snapshot.text))
+        .unwrap_or_default()
+}
+
+pub(crate) fn find_table(path: &str, as_of: Option<&str>, columns: &[&str]) -> Option<CorpusTable> {
+    extract_tables(path, as_of)
+        .into_iter()
+        .find(|table| table.has_columns(columns))
+}
+
+pub(crate) fn corpus_paths() -> Vec<&'static str> {
+    let mut paths: Vec<&'static str> = Vec::new();
+    for snapshot in SNAPSHOTS {
+        if !paths.contains(&snapshot.path) {
+            paths.push(snapshot.path);
+        }
+    }
+    paths
+}
+
+fn row_matches(table: &CorpusTable, row: &TableRow, filters: &Map<String, Value>, exact: bool) -> bool {
+    filters.iter().all(|(column, expected)| {
+        let Some(actual) = table.cell(row, column) else {
+            return false;
+        };
+        let expected = match expected {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+        if exact {
+            actual == expected.trim()
+        } else {
+            actual.to_lowercase().contains(&expected.trim().to_lowercase())
+        }
+    })
+}
+
+pub(crate) fn query_tables(
+    file: Option<&str>,
+    section: Option<&str>,
+    filters: &Map<String, Value>,
+    exact: bool,
+    limit: usize,
+    as_of: Option<&str>,
+) -> Value {
+    let paths: Vec<&'static str> = match file {
+        Some(file) => corpus_paths()
+            .into_iter()
+            .filter(|path| *path == file || path.rsplit('/').next() == Some(file))
+            .collect(),
+        None => corpus_paths(),
+    };
+    let section_filter = section.map(str::to_lowercase);
+    let list_only = section.is_none() && filters.is_empty();
+
+    let mut tables_json = Vec::new();
+    let mut total_rows = 0;
+
+    for path in paths {
+        for table in extract_tables(path, as_of) {
+            if let Some(wanted) = &section_filter {
+                if !table.section.to_lowercase().contains(wanted.as_str()) {
+                    continue;
+                }
+            }
+            if filters.keys().any(|column| table.column(column).is_none()) {
+                continue;
+            }
+
+            if list_only {
+                tables_json.push(json!({
+                    "file": table.file,
+                    "line": table.line,
+                    "page": table.page,
+                    "section": table.section,
+                    "headers": table.headers,
+                    "row_count": table.rows.len(),
+                    "citation": table.citation(),
+                }));
+                continue;
+            }
+
+            let rows: Vec<Value> = table
+                .rows
+                .iter()
+                .filter(|row| row_matches(&table, row, filters, exact))
+                .take(limit.saturating_sub(total_rows))
+                .map(|row| {
+                    json!({
+                        "line": row.line,
+                        "values": table.typed_row(row),
+                        "ragged": table.is_ragged(row),
+                    })
+                })
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+            total_rows += rows.len();
+            tables_json.push(json!({
+                "file": table.file,
+                "line": table.line,
+                "page": table.page,
+                "section": table.section,
+                "headers": table.headers,
+                "citation": table.citation(),
+                "rows": rows,
+            }));
+            if total_rows >= limit {
+                break;
+            }
+        }
+        if !list_only && total_rows >= limit {
+            break;
+        }
+    }
+
+    json!({
+        "file": file,
+        "table": section,
+        "filters": filters,
+        "match": if exact { "exact" } else { "contains" },
+        "as_of": as_of,
+        "mode": if list_only { "catalog" } else { "rows" },
+        "table_count": tables_json.len(),
+        "row_count": total_rows,
+        "tables": tables_json,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "## p.3\n\n### 계열별 수강학점\n\n| 계열 | 학점 | 비고 |\n|---|:---:|---|\n\
+| 이공 | 18 | - |\n| 인문 | 19 |\n| 예체능 | 21 | 실기 | 추가 |\n\n본문\n";
+
+    fn sample() -> CorpusTable {
+        extract_tables_from_text("/knowledge/normalized-md/sample.md", SAMPLE).remove(0)
+    }
+
+    #[test]
+    fn tables_keep_page_section_and_headers() {
+        let table = sample();
+        assert_eq!(table.page.as_deref(), Some("3"));
+        assert_eq!(table.section, "계열별 수강학점");
+        assert_eq!(table.line, 5);
+        assert!(table.has_columns(&["계열", "학점"]));
+        assert!(!table.has_columns(&["계열", "학년"]));
+        assert_eq!(table.cell(&table.rows[0], "학점"), Some("18"));
+        assert_eq!(table.typed_row(&table.rows[0])["학점"], json!(18));
+        assert_eq!(table.typed_row(&table.rows[0])["비고"], Value::Null);
+    }
+
+    #[test]
+    fn ragged_rows_are_reported_as_skipped() {
+        let table = sample();
+        let skipped = table.skipped_rows();
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(skipped[0]["line"], 8);
+        assert_eq!(skipped[0]["cells"], json!(["인문", "19"]));
+        assert_eq!(skipped[0]["expected_columns"], 3);
+        assert_eq!(skipped[1]["line"], 9);
+    }
+
+    #[test]
+    fn header_filters_match_by_column() {
+        let table = sample();
+        let mut filters = Map::new();
+        filters.insert("계열".to_string(), json!("이공"));
+        assert!(row_matches(&table, &table.rows[0], &filters, true));
+        assert!(!row_matches(&table, &table.rows[1], &filters, true));
+        filters.insert("학점".to_string(), json!(18));
+        assert!(row_matches(&table, &table.rows[0], &filters, true));
+        filters.insert("학년".to_string(), json!("1"));
+        assert!(!row_matches(&table, &table.rows[0], &filters, false));
+    }
+
+    #[test]
+    fn file_filter_accepts_a_full_path_or_a_bare_filename() {
+        let full = query_tables(Some(crate::PATH_CALENDAR), None, &Map::new(), false, 50, None);
+        let bare = query_tables(Some("학사 일정.md"), None, &Map::new(), false, 50, None);
+        assert!(full["table_count"].as_u64().unwrap_or_default() > 0);
+        assert_eq!(full["tables"], bare["tables"]);
+        assert!(bare["tables"]
+            .as_array()
+            .expect("tables")
+            .iter()
+            .all(|table| table["file"] == crate::PATH_CALENDAR));
+
+        let unknown = query_tables(Some("없는 문서.md"), None, &Map::new(), false, 50, None);
+        assert_eq!(unknown["table_count"], 0);
+    }
+}
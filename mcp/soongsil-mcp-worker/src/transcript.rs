@@ -0,0 +1,337 @@
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::elective::ElectiveCourse;
+use crate::gpa::{semester_order, UNDATED};
+use crate::{optional_string, required_i64, required_string, CreditTotals};
+
+pub(crate) const GRADES: &[&str] = &[
+    "A+", "A0", "A-", "B+", "B0", "B-", "C+", "C0", "C-", "D+", "D0", "D-", "F", "P", "NP",
+];
+
+#[derive(Clone)]
+pub(crate) struct Course {
+    pub index: usize,
+    pub name: String,
+    pub credits: i64,
+    pub category: String,
+    pub bucket: &'static str,
+    pub grade: Option<String>,
+    pub semester: Option<String>,
+    pub balance_area: Option<String>,
+    pub offering_college: Option<String>,
+}
+
+impl Course {
+    pub(crate) fn key(&self) -> String {
+        course_key(&self.name)
+    }
+
+    pub(crate) fn passed(&self) -> bool {
+        matches!(self.grade.as_deref(), Some(grade) if grade != "F" && grade != "NP")
+    }
+
+    pub(crate) fn failed(&self) -> bool {
+        matches!(self.grade.as_deref(), Some("F") | Some("NP"))
+    }
+}
+
+pub(crate) struct Audit {
+    pub totals: CreditTotals,
+    pub counted: Vec<Course>,
+    pub summary: Value,
+    pub notes: Vec<String>,
+}
+
+pub(crate) fn course_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '·' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+pub(crate) fn classify_category(raw: &str) -> &'static str {
+    let compact: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+    match compact.as_str() {
+        "교필" | "교양필수" => "교양필수",
+        "교선" | "교양선택" => "교양선택",
+        "전기" | "전공기초" => "전공기초",
+        "전필" | "전선" | "전공" | "전공필수" | "전공선택" => "전공",
+        "복필" | "복선" | "복전" | "복수전공" | "복수전공필수" | "복수전공선택" => "복수전공",
+        "부필" | "부선" | "부전공" | "부전공필수" | "부전공선택" => "부전공",
+        "일선" | "일반선택" | "자유선택" => "일반선택",
+        _ => "미분류",
+    }
+}
+
+pub(crate) fn normalize_grade(raw: &str) -> Option<String> {
+    let grade = raw
+        .trim()
+        .to_uppercase()
+        .replace(['°', 'O'], "0");
+    let grade = match grade.as_str() {
+        "A" | "B" | "C" | "D" => format!("{grade}0"),
+        "PASS" => "P".to_string(),
+        "FAIL" => "F".to_string(),
+        _ => grade,
+    };
+    GRADES.contains(&grade.as_str()).then_some(grade)
+}
+
+pub(crate) fn parse_courses(args: &Map<String, Value>) -> std::result::Result<Vec<Course>, String> {
+    let items = args
+        .get("courses")
+        .and_then(Value::as_array)
+        .ok_or("'courses' is required and must be an array")?;
+
+    let mut courses = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        let object = item
+            .as_object()
+            .ok_or_else(|| format!("courses[{index}] must be an object"))?;
+        let prefixed = |message: String| format!("courses[{index}]: {message}");
+        let name = required_string(object, "name").map_err(prefixed)?;
+        let credits = required_i64(object, "credits").map_err(prefixed)?;
+        if credits <= 0 {
+            return Err(prefixed("'credits' must be a positive integer".to_string()));
+        }
+        let category = required_string(object, "category").map_err(prefixed)?;
+        let grade = match optional_string(object, "grade").filter(|g| !g.trim().is_empty()) {
+            Some(raw) => Some(normalize_grade(&raw).ok_or_else(|| {
+                prefixed(format!(
+                    "'grade' must be one of {} (got '{raw}')",
+                    GRADES.join("/")
+                ))
+            })?),
+            None => None,
+        };
+
+        courses.push(Course {
+            index,
+            bucket: classify_category(&category),
+            name: name.trim().to_string(),
+            credits,
+            category,
+            grade,
+            semester: optional_string(object, "semester").map(|s| s.trim().to_string()),
+            balance_area: optional_string(object, "balance_area").map(|s| s.trim().to_string()),
+            offering_college: optional_string(object, "offering_college").map(|s| s.trim().to_string()),
+        });
+    }
+
+    Ok(courses)
+}
+
+pub(crate) fn elective_courses(counted: &[Course]) -> Vec<ElectiveCourse> {
+    counted
+        .iter()
+        .filter(|course| course.bucket == "교양선택")
+        .filter_map(|course| {
+            Some(ElectiveCourse {
+                name: course.name.clone(),
+                area: course.balance_area.clone().filter(|area| !area.is_empty())?,
+                credits: Some(course.credits),
+                offering_college: course.offering_college.clone(),
+            })
+        })
+        .collect()
+}
+
+fn attempt_order(course: &Course) -> ((i64, u8, String), usize) {
+    (semester_order(course.semester.as_deref().unwrap_or(UNDATED)), course.index)
+}
+
+fn course_json(course: &Course, status: &str) -> Value {
+    json!({
+        "name": course.name,
+        "credits": course.credits,
+        "category": course.category,
+        "bucket": course.bucket,
+        "grade": course.grade,
+        "semester": course.semester,
+        "balance_area": course.balance_area,
+        "status": status,
+    })
+}
+
+pub(crate) fn audit(courses: &[Course], major_type: &str) -> Audit {
+    let mut attempts: BTreeMap<String, Vec<&Course>> = BTreeMap::new();
+    for course in courses {
+        attempts.entry(course.key()).or_default().push(course);
+    }
+
+    let mut status: Vec<&str> = vec!["취득"; courses.len()];
+    let mut counted = Vec::new();
+    let mut retaken = Vec::new();
+    let mut duplicates = Vec::new();
+
+    for group in attempts.values_mut() {
+        group.sort_by_key(|course| attempt_order(course));
+        let latest_pass = group.iter().rev().find(|course| course.passed()).copied();
+
+        for course in group.iter() {
+            status[course.index] = if course.grade.is_none() {
+                "수강중"
+            } else if course.failed() {
+                "미취득(F)"
+            } else if latest_pass.map(|pass| pass.index) == Some(course.index) {
+                "취득"
+            } else {
+                "재수강 대체"
+            };
+        }
+        if let Some(pass) = latest_pass {
+            counted.push(pass.clone());
+        }
+
+        let graded: Vec<&&Course> = group.iter().filter(|course| course.grade.is_some()).collect();
+        if graded.len() > 1 {
+            retaken.push(json!({
+                "name": group[0].name,
+                "attempts": graded
+                    .iter()
+                    .map(|course| json!({ "semester": course.semester, "grade": course.grade }))
+                    .collect::<Vec<_>>(),
+                "counted_semester": latest_pass.and_then(|pass| pass.semester.clone()),
+            }));
+        }
+        for pair in group.windows(2) {
+            if pair[0].semester.is_some() && pair[0].semester == pair[1].semester {
+                duplicates.push(json!({ "name": pair[1].name, "semester": pair[1].semester }));
+            }
+        }
+    }
+    counted.sort_by_key(|course| course.index);
+
+    let mut by_bucket: BTreeMap<&str, i64> = BTreeMap::new();
+    for course in &counted {
+        *by_bucket.entry(course.bucket).or_default() += course.credits;
+    }
+    let bucket = |name: &str| by_bucket.get(name).copied().unwrap_or(0);
+    let major_bucket = if major_type == "복수전공자(복수전공)" { "복수전공" } else { "전공" };
+    let totals = CreditTotals {
+        liberal_required: bucket("교양필수"),
+        liberal_elective: bucket("교양선택"),
+        major_basic: bucket("전공기초"),
+        major: bucket(major_bucket),
+        total: by_bucket.values().sum(),
+    };
+
+    let failed: Vec<Value> = courses
+        .iter()
+        .filter(|course| course.failed())
+        .map(|course| json!({ "name": course.name, "semester": course.semester, "grade": course.grade }))
+        .collect();
+    let in_progress: Vec<&str> = courses
+        .iter()
+        .filter(|course| course.grade.is_none())
+        .map(|course| course.name.as_str())
+        .collect();
+    let unclassified: Vec<Value> = courses
+        .iter()
+        .filter(|course| course.bucket == "미분류")
+        .map(|course| json!({ "name": course.name, "category": course.category }))
+        .collect();
+
+    let mut notes = vec![
+        "학사과정은 D- 이상과 P를 취득학점으로 인정 (학칙.pdf, p.12, 제53조 4항)".to_string(),
+        "같은 과목명의 중복 이수는 가장 최근 학기의 취득 성적 1회만 학점에 합산".to_string(),
+    ];
+    if !retaken.is_empty() {
+        notes.push(
+            "재수강 성적 대체 방식과 횟수 한도는 코퍼스에 근거 문서가 없음(source-map Known Gaps)".to_string(),
+        );
+    }
+    if !in_progress.is_empty() {
+        notes.push("성적이 없는 과목은 수강중으로 보고 합산에서 제외".to_string());
+    }
+    if !unclassified.is_empty() {
+        notes.push("이수구분을 인식하지 못한 과목은 졸업학점에만 합산".to_string());
+    }
+
+    let summary = json!({
+        "course_count": courses.len(),
+        "counted_course_count": counted.len(),
+        "credits_by_category": by_bucket,
+        "major_bucket": major_bucket,
+        "courses": courses
+            .iter()
+            .map(|course| course_json(course, status[course.index]))
+            .collect::<Vec<_>>(),
+        "failed": failed,
+        "retaken": retaken,
+        "duplicate_entries": duplicates,
+        "in_progress": in_progress,
+        "unclassified": unclassified,
+    });
+
+    Audit {
+        totals,
+        counted,
+        summary,
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn courses(items: Value) -> Vec<Course> {
+        let args = json!({ "courses": items });
+        parse_courses(args.as_object().expect("object")).expect("valid courses")
+    }
+
+    #[test]
+    fn latest_passing_attempt_is_counted() {
+        let courses = courses(json!([
+            { "name": "자료구조", "credits": 3, "category": "전필", "grade": "C0", "semester": "2023-1" },
+            { "name": "자료 구조", "credits": 3, "category": "전필", "grade": "A+", "semester": "2024-1" },
+            { "name": "자료구조", "credits": 3, "category": "전필", "grade": "F", "semester": "2024-2" },
+            { "name": "회계원리", "credits": 3, "category": "일선", "grade": "B+", "semester": "2023-2" },
+        ]));
+        let audit = audit(&courses, "단일전공자");
+
+        assert_eq!(audit.counted.len(), 2);
+        assert_eq!(audit.counted[0].semester.as_deref(), Some("2024-1"));
+        assert_eq!(audit.counted[0].grade.as_deref(), Some("A+"));
+        assert_eq!(audit.totals.major, 3);
+        assert_eq!(audit.totals.total, 6);
+
+        let statuses: Vec<&str> = audit.summary["courses"]
+            .as_array()
+            .expect("courses")
+            .iter()
+            .map(|course| course["status"].as_str().unwrap_or_default())
+            .collect();
+        assert_eq!(statuses, vec!["재수강 대체", "취득", "미취득(F)", "취득"]);
+        assert_eq!(audit.summary["retaken"][0]["counted_semester"], "2024-1");
+        assert_eq!(audit.summary["failed"].as_array().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn same_semester_duplicates_are_flagged() {
+        let courses = courses(json!([
+            { "name": "대학글쓰기", "credits": 3, "category": "교필", "grade": "B0", "semester": "2024-1" },
+            { "name": "대학글쓰기", "credits": 3, "category": "교필", "grade": "B0", "semester": "2024-1" },
+        ]));
+        let audit = audit(&courses, "단일전공자");
+
+        assert_eq!(audit.totals.liberal_required, 3);
+        assert_eq!(
+            audit.summary["duplicate_entries"],
+            json!([{ "name": "대학글쓰기", "semester": "2024-1" }])
+        );
+    }
+
+    #[test]
+    fn invalid_grades_are_rejected_with_the_course_index() {
+        let args = json!({ "courses": [
+            { "name": "미적분", "credits": 3, "category": "전기", "grade": "A" },
+            { "name": "물리", "credits": 3, "category": "전기", "grade": "E" },
+        ]});
+        let error = parse_courses(args.as_object().expect("object")).err().expect("invalid grade");
+        assert!(error.starts_with("courses[1]: 'grade' must be one of"));
+    }
+}
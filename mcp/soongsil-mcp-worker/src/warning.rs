@@ -0,0 +1,265 @@
+use serde_json::{json, Map, Value};
+
+use crate::early::extract_first_decimal;
+use crate::gpa::{format_gpa, round2, GpaReport, UNDATED};
+use crate::load::parse_term;
+use crate::{extract_first_int, numeric_rule, required_string};
+
+pub(crate) struct SemesterRecord {
+    pub label: String,
+    pub gpa: Option<f64>,
+    pub attended: bool,
+}
+
+pub(crate) fn parse_semester_records(args: &Map<String, Value>) -> std::result::Result<Vec<SemesterRecord>, String> {
+    let items = args
+        .get("semesters")
+        .and_then(Value::as_array)
+        .ok_or("'semesters' or 'courses' is required and 'semesters' must be an array")?;
+    if items.is_empty() {
+        return Err("'semesters' must contain at least one semester".to_string());
+    }
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let object = item
+                .as_object()
+                .ok_or_else(|| format!("semesters[{index}] must be an object"))?;
+            let gpa = match object.get("gpa") {
+                None | Some(Value::Null) => None,
+                Some(value) => Some(
+                    value
+                        .as_f64()
+                        .filter(|gpa| (0.0..=4.5).contains(gpa))
+                        .ok_or_else(|| format!("semesters[{index}]: 'gpa' must be a number between 0 and 4.5"))?,
+                ),
+            };
+            let label = required_string(object, "semester")
+                .map_err(|message| format!("semesters[{index}]: {message}"))?
+                .trim()
+                .to_string();
+            if parse_term(&label).is_none() {
+                return Err(format!(
+                    "semesters[{index}]: 'semester' must be a term such as 2024-1, 2024-2 or 2024-여름 (got '{label}')"
+                ));
+            }
+            Ok(SemesterRecord {
+                label,
+                gpa,
+                attended: object.get("attended").and_then(Value::as_bool).unwrap_or(true),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn records_from_report(report: &GpaReport) -> Vec<SemesterRecord> {
+    report
+        .semesters
+        .iter()
+        .filter(|semester| semester.label != UNDATED)
+        .map(|semester| SemesterRecord {
+            label: semester.label.clone(),
+            gpa: semester.gpa,
+            attended: true,
+        })
+        .collect()
+}
+
+fn is_seasonal(label: &str) -> bool {
+    matches!(parse_term(label), Some((_, "여름학기" | "겨울학기")))
+}
+
+fn dismissal_risk(streak: i64, limit: i64) -> &'static str {
+    if streak >= limit {
+        "제적 대상"
+    } else if streak == limit - 1 {
+        "다음 학기 경고 시 제적"
+    } else if streak > 0 {
+        "주의"
+    } else {
+        "없음"
+    }
+}
+
+pub(crate) fn evaluate_academic_standing(
+    records: &[SemesterRecord],
+    level: Option<&str>,
+    as_of: Option<&str>,
+) -> Value {
+    let (Some((threshold, warning_citation)), Some((limit, dismissal_citation))) = (
+        numeric_rule("학사경고 기준", as_of)
+            .and_then(|(value, citation)| Some((extract_first_decimal(&value)?, citation))),
+        numeric_rule("제적 연계", as_of).and_then(|(value, citation)| Some((extract_first_int(&value)?, citation))),
+    ) else {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "law-numeric-rules.md에서 학사경고 기준 또는 제적 연계 행을 찾지 못함",
+            "citation": "(학칙.pdf, p.13, 제54조 1항)"
+        });
+    };
+    if level == Some("대학원") {
+        return json!({
+            "judgement": "판정 불가",
+            "reason": "학사경고와 연속 학사경고 제적은 학사과정에만 적용",
+            "citation": warning_citation,
+        });
+    }
+
+    let mut streak = 0;
+    let mut max_streak = 0;
+    let mut warnings = Vec::new();
+    let mut skipped = Vec::new();
+    let semesters: Vec<Value> = records
+        .iter()
+        .map(|record| {
+            let (warning, reason) = if is_seasonal(&record.label) {
+                (None, "계절학기: 매학기 학사경고 판정 대상 아님".to_string())
+            } else if !record.attended {
+                (None, "등록금 납부 후 수강하지 않은 학기: 학사경고 제외".to_string())
+            } else {
+                match record.gpa {
+                    None => (None, "평점 없음(휴학 등): 경고 판정 없이 건너뜀".to_string()),
+                    Some(gpa) if gpa < threshold => (Some(true), format!("평균평점 {} < {threshold:.2}", format_gpa(gpa))),
+                    Some(gpa) => (Some(false), format!("평균평점 {} ≥ {threshold:.2}", format_gpa(gpa))),
+                }
+            };
+            match warning {
+                Some(true) => {
+                    streak += 1;
+                    max_streak = max_streak.max(streak);
+                    warnings.push(record.label.clone());
+                }
+                Some(false) => streak = 0,
+                None => skipped.push(record.label.clone()),
+            }
+            json!({
+                "semester": record.label,
+                "gpa": record.gpa.map(round2),
+                "gpa_raw": record.gpa,
+                "warning": warning,
+                "streak": streak,
+                "reason": reason,
+                "citation": warning.map(|_| warning_citation.as_str()),
+            })
+        })
+        .collect();
+
+    let risk = dismissal_risk(streak, limit);
+    let mut notes = vec![
+        format!("학사경고는 학사과정에서 매학기 평균평점 {threshold:.2} 미만일 때 부과 {warning_citation}"),
+        format!("학사경고 연속 {limit}회 이상은 제적 사유 {dismissal_citation}"),
+        "학사경고를 받은 다음 학기에는 수강학점이 제한될 수 있으며 세부 기준은 시행세칙 소관 (학칙.pdf, p.13, 제54조 3항)".to_string(),
+    ];
+    if !skipped.is_empty() {
+        notes.push(format!(
+            "경고 판정을 건너뛴 학기({})는 연속 횟수를 끊거나 늘리지 않는 것으로 계산: 휴학을 사이에 둔 연속 여부 기준은 코퍼스에 없음",
+            skipped.join(", ")
+        ));
+    }
+    if max_streak >= limit && streak < limit {
+        notes.push(format!(
+            "과거에 연속 {max_streak}회 경고 이력이 있음: 재입학 등 학적 변동 여부 확인 필요"
+        ));
+    }
+    if risk == "제적 대상" {
+        notes.push("제적 대상이 되면 사전에 배달증명으로 본인에게 통보하고 공지 (학칙.pdf, p.7, 제29조 3항)".to_string());
+    }
+    if level.is_none() {
+        notes.push("level 미입력: 학사과정으로 가정".to_string());
+    }
+
+    json!({
+        "semesters": semesters,
+        "warnings": warnings,
+        "warning_count": warnings.len(),
+        "current_streak": streak,
+        "max_streak": max_streak,
+        "dismissal_limit": limit,
+        "dismissal_risk": risk,
+        "next_semester": {
+            "min_gpa": threshold,
+            "requirement": format!("다음 정규학기 평균평점 {threshold:.2} 이상이면 학사경고 없음, 연속 횟수 0으로 초기화"),
+            "streak_if_warned": streak + 1,
+            "dismissal_if_warned": streak + 1 >= limit,
+            "citation": warning_citation,
+        },
+        "citation": dismissal_citation,
+        "notes": notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(items: &[(&str, Option<f64>)]) -> Vec<SemesterRecord> {
+        items
+            .iter()
+            .map(|(label, gpa)| SemesterRecord {
+                label: label.to_string(),
+                gpa: *gpa,
+                attended: true,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skipped_semesters_keep_the_streak() {
+        let result = evaluate_academic_standing(
+            &records(&[
+                ("2023-1", Some(1.2)),
+                ("2023-여름", Some(0.5)),
+                ("2023-2", Some(1.495)),
+                ("2024-1", None),
+                ("2024-2", Some(1.4)),
+            ]),
+            None,
+            None,
+        );
+        assert_eq!(result["warnings"], json!(["2023-1", "2023-2", "2024-2"]));
+        assert_eq!(result["current_streak"], 3);
+        assert_eq!(result["dismissal_limit"], 3);
+        assert_eq!(result["dismissal_risk"], "제적 대상");
+        assert_eq!(result["semesters"][2]["gpa"], 1.5);
+        assert_eq!(result["semesters"][2]["warning"], true);
+    }
+
+    #[test]
+    fn gpa_at_threshold_resets_the_streak() {
+        let result = evaluate_academic_standing(
+            &records(&[("2023-1", Some(1.0)), ("2023-2", Some(1.0)), ("2024-1", Some(1.5)), ("2024-2", Some(1.0))]),
+            None,
+            None,
+        );
+        assert_eq!(result["max_streak"], 2);
+        assert_eq!(result["current_streak"], 1);
+        assert_eq!(result["dismissal_risk"], "주의");
+        assert_eq!(result["next_semester"]["streak_if_warned"], 2);
+        assert_eq!(result["next_semester"]["dismissal_if_warned"], false);
+    }
+
+    fn parse(semesters: Value) -> std::result::Result<Vec<SemesterRecord>, String> {
+        let args = json!({ "semesters": semesters });
+        parse_semester_records(args.as_object().expect("object"))
+    }
+
+    #[test]
+    fn semester_input_errors_name_the_offending_field() {
+        assert_eq!(parse(json!([])).err().as_deref(), Some("'semesters' must contain at least one semester"));
+        assert_eq!(
+            parse(json!([{ "semester": "2024-1", "gpa": 1.2 }, { "semester": "2024 봄", "gpa": 1.0 }]))
+                .err()
+                .as_deref(),
+            Some("semesters[1]: 'semester' must be a term such as 2024-1, 2024-2 or 2024-여름 (got '2024 봄')")
+        );
+        assert_eq!(
+            parse(json!([{ "semester": "2024-1", "gpa": 4.6 }])).err().as_deref(),
+            Some("semesters[0]: 'gpa' must be a number between 0 and 4.5")
+        );
+        let parsed = parse(json!([{ "semester": " 2024-여름 ", "attended": false }])).expect("valid semester");
+        assert_eq!(parsed[0].label, "2024-여름");
+        assert!(parsed[0].gpa.is_none());
+        assert!(!parsed[0].attended);
+    }
+}